@@ -71,6 +71,76 @@ enum Enum {
 }
 ```
 
+#### Rename All Variants or Fields
+
+The `rename_all` attribute can convert the names of all fields of a struct or a variant, or the names of all variants of an enum, to `camelCase`, `snake_case`, `SCREAMING_SNAKE_CASE` or `kebab-case`. An explicit `name` attribute still takes precedence.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(Debug(rename_all = "camelCase"))]
+struct Struct {
+    field_one: u8,
+    #[educe(Debug(name = "f"))]
+    field_two: u8,
+}
+
+#[derive(Educe)]
+#[educe(Debug(rename_all = "snake_case"))]
+enum Enum {
+    VariantOne,
+    #[educe(Debug(rename_all = "kebab-case"))]
+    VariantTwo {
+        field_one: u8,
+    },
+}
+```
+
+#### Variant Styles
+
+The `style` attribute of an enum controls how its variants are named. `"variant"` prints only the variant name (the default), `"qualified"` prints the enum name together with the variant name, and `"discriminant"` prints the numeric discriminant of each variant. The `"discriminant"` style can only be used for enums whose variants are all unit variants, and the value is printed as the `#[repr]` integer type of the enum (or `isize`). A variant with an explicit `name` still prints its name.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(Debug(style = "qualified"))]
+enum Enum {
+    V1,
+    V2(u8),
+}
+
+#[derive(Educe)]
+#[educe(Debug(style = "discriminant"))]
+#[repr(u8)]
+enum Opcode {
+    Read = 1,
+    Write = 2,
+}
+```
+
+#### Print Generic Arguments
+
+The `with_generics` attribute appends the concrete generic arguments of the type to the printed name, by using `core::any::type_name` for the type parameters. Lifetime parameters are omitted. Set it to `"short"` to strip the module paths from the type names.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(Debug(with_generics, bound))]
+struct Struct<T> {
+    f1: T,
+}
+
+#[derive(Educe)]
+#[educe(Debug(with_generics = "short", bound))]
+struct Wrapper<T>(T);
+
+assert_eq!("Struct<u8> { f1: 1 }", format!("{:?}", Struct { f1: 1u8 }));
+assert_eq!("Wrapper<Option<u8>>(None)", format!("{:?}", Wrapper(None::<u8>)));
+```
+
 #### Ignore Fields
 
 The `ignore` attribute can ignore specific fields.
@@ -100,6 +170,36 @@ enum Enum {
 }
 ```
 
+#### Mark Ignored Fields
+
+The `non_exhaustive` attribute makes the output end with `..` if any field has been ignored, so that the output doesn't look complete when it isn't. Setting it on an enum applies it to all variants, and each variant can still override it.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(Debug(non_exhaustive))]
+struct Struct {
+    #[educe(Debug(ignore))]
+    f1: u8,
+    f2: u8,
+}
+
+#[derive(Educe)]
+#[educe(Debug(non_exhaustive))]
+enum Enum {
+    V1 {
+        #[educe(Debug(ignore))]
+        f1: u8,
+    },
+    #[educe(Debug(non_exhaustive = false))]
+    V2(
+        #[educe(Debug(ignore))]
+        u8
+    ),
+}
+```
+
 #### Fake Structs and Tuples
 
 With the `named_field` attribute, structs can be formatted as tuples and tuples can be formatted as structs.
@@ -169,6 +269,93 @@ enum Enum<T: A> {
 }
 ```
 
+#### Limit the Output of Long Fields
+
+The `max_items` attribute can be used to print only the first N elements of a field whose reference implements `IntoIterator`, followed by a `... (N more)` marker. The `max_len` attribute does the same thing for the characters of a `str` or `String` field.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(Debug)]
+struct Struct {
+    #[educe(Debug(max_items = 16))]
+    f1: Vec<u8>,
+    #[educe(Debug(max_len = 64))]
+    f2: String,
+}
+```
+
+#### Sort Maps and Sets
+
+The `sorted` attribute prints a map or a set field with its entries sorted by key, so that the output of a `HashMap` or a `HashSet` is deterministic. The key must implement `Ord`. A collection whose reference iterates over `(&K, &V)` pairs is printed as a map, and any other collection is printed as a set, keeping equal entries. No allocation is needed, but the collection is iterated again for every distinct key, so this attribute is meant for maps and sets of moderate size.
+
+```rust
+#[macro_use] extern crate educe;
+
+use std::collections::{HashMap, HashSet};
+
+#[derive(Educe)]
+#[educe(Debug)]
+struct Struct {
+    #[educe(Debug(sorted))]
+    f1: HashMap<u8, char>,
+    #[educe(Debug(sorted))]
+    f2: HashSet<u8>,
+}
+
+let s = Struct {
+    f1: vec![(2, 'b'), (1, 'a')].into_iter().collect(),
+    f2: vec![3, 1, 2].into_iter().collect(),
+};
+
+assert_eq!("Struct { f1: {1: 'a', 2: 'b'}, f2: {1, 2, 3} }", format!("{:?}", s));
+```
+
+#### Flatten Nested Structs
+
+The `flatten` attribute can be used to list the fields of a nested struct directly in the outer struct. The type of the field needs to be a struct which also uses `#[educe(Debug)]` and is formatted with named fields.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(Debug)]
+struct Header {
+    id: u64,
+    ts: u64,
+}
+
+#[derive(Educe)]
+#[educe(Debug)]
+struct Message {
+    #[educe(Debug(flatten))]
+    header: Header,
+    body: u8,
+}
+```
+
+#### Limit the Depth of Recursive Structures
+
+The `max_depth` attribute prints `..` instead of a value once the value is nested `max_depth` levels deep in values of types with the `max_depth` attribute. It is useful for recursive types such as trees and ASTs, including mutually recursive ones. `max_depth` needs to be between 1 and 64.
+
+The depth is carried to the fields through the fill character of the formatter. The precision and the `#` flag are kept, but the `x?` and `X?` flags are lost. If a width, the `+` flag or the `0` flag is given, these options are kept instead, and the depth starts again from zero inside the value.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(Debug(max_depth = 2))]
+enum Expr {
+    Literal(u8),
+    Neg(Box<Expr>),
+}
+
+let expr = Expr::Neg(Box::new(Expr::Neg(Box::new(Expr::Literal(1)))));
+
+assert_eq!("Neg(Neg(..))", format!("{:?}", expr));
+```
+
 #### Generic Parameters Bound to the `Debug` Trait or Others
 
 The `#[educe(Debug(bound))]` attribute can be used to add the `Debug` trait bound to all generaic parameters for the `Debug` implementation.
@@ -227,20 +414,72 @@ enum Enum<T, K> {
 
 A union will be formatted to a `u8` slice, because we don't know it's field at runtime. The fields of a union cannot be ignored, renamed or formated with other methods or traits.
 
+Reading the bytes is only sound when none of them can be uninitialized, so by default every field must be a primitive number, `bool`, `char`, or an array of them, and all fields must have the same size, which is checked at compile time. Otherwise, use the `opaque` attribute to print the union without its content, or the `unsafe_bytes` attribute to keep the byte dump if you can guarantee that every byte is always initialized.
+
 ```rust
 #[macro_use] extern crate educe;
 
 #[derive(Educe)]
 #[educe(Debug)]
-struct Union {
+union Union {
+    f1: [u8; 4],
+    f2: u32,
+}
+
+#[derive(Educe)]
+#[educe(Debug(opaque))]
+union Union2 {
     f1: u8,
-    f2: i32,
+    f2: (u8, u16),
 }
+
+assert_eq!("Union([1, 0, 0, 0])", format!("{:?}", Union { f1: [1, 0, 0, 0] }));
+assert_eq!("Union2 { .. }", format!("{:?}", Union2 { f1: 1 }));
+```
+
+#### Tagged Unions
+
+If the active field of a union can be known at runtime, the `union_tag` attribute can be set to the path of a function which takes `&Self` and returns the name of the active field. The union will then be formatted with its active field. The same `union_tag` attribute also enables the `PartialEq`, `Hash` and `Clone` traits for the union.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+struct Small {
+    tag: u8,
+    value: u8,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+struct Large {
+    tag: u8,
+    value: u32,
+}
+
+#[derive(Educe)]
+#[educe(Debug, union_tag = "Self::active_field")]
+union Union {
+    small: Small,
+    large: Large,
+}
+
+impl Union {
+    fn active_field(&self) -> &'static str {
+        match unsafe { self.small.tag } {
+            0 => "small",
+            _ => "large",
+        }
+    }
+}
+
+assert_eq!("Union { small: Small { tag: 0, value: 1 } }", format!("{:?}", Union { small: Small { tag: 0, value: 1 } }));
 ```
 
 ## PartialEq
 
-Use `#[derive(Educe)]` and `#[educe(ParitalEq)]` to implement the `ParitalEq` trait for a struct or an enum. It supports to ignore some fields, or set a trait and/or a method to replace the `ParitalEq` trait used by default.
+Use `#[derive(Educe)]` and `#[educe(ParitalEq)]` to implement the `ParitalEq` trait for a struct, an enum, or a union. It supports to ignore some fields, or set a trait and/or a method to replace the `ParitalEq` trait used by default.
 
 #### Basic Usage
 
@@ -390,6 +629,177 @@ enum Enum<T, K> {
 }
 ```
 
+#### Total Equality for Floats
+
+An `f32` or `f64` field can add a `#[educe(PartialEq(total))]` attribute. All `NaN` values are then equal to each other, and `-0.0` is equal to `0.0`, so the `Eq` trait can be implemented as well. The same `total` parameter of the `PartialOrd`, `Ord` and `Hash` traits handles `NaN` and `-0.0` in the same way.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Point {
+    #[educe(PartialEq(total), PartialOrd(total), Ord(total), Hash(total))]
+    x: f64,
+    #[educe(PartialEq(total), PartialOrd(total), Ord(total), Hash(total))]
+    y: f64,
+}
+```
+
+#### Approximate Equality for Floats
+
+A float field, or an array or a `Vec` of floats, can add a `#[educe(PartialEq(approx = tolerance))]` attribute to be equal when the absolute difference is not greater than `tolerance`. Use `#[educe(PartialEq(approx(relative = tolerance)))]` to scale the tolerance with the larger magnitude of the two values instead. Because such an equality is not transitive, the `approx` parameter cannot be used if the `Eq` or `Hash` trait is also implemented by Educe.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(PartialEq)]
+struct Particle {
+    #[educe(PartialEq(approx = 1e-9))]
+    mass: f64,
+    #[educe(PartialEq(approx(relative = 1e-6)))]
+    position: [f64; 3],
+}
+```
+
+#### Compare by a Key
+
+A field can add a `#[educe(PartialEq(by = "expression"))]` attribute, where `expression` is a function or a closure which takes a reference to the field and returns a key. The keys of the two fields are compared instead of the fields themselves. The `PartialOrd`, `Ord` and `Hash` traits have the same `by` parameter, so with the same key all of them stay consistent.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(PartialEq, Eq, Hash)]
+struct User {
+    #[educe(
+        PartialEq(by = "|name: &String| name.to_lowercase()"),
+        Hash(by = "|name: &String| name.to_lowercase()")
+    )]
+    name: String,
+}
+```
+
+#### Compare by Identity
+
+A field of a smart pointer such as `Rc<T>`, `Arc<T>` or `Box<T>`, a reference, or a raw pointer, can add a `#[educe(PartialEq(ptr))]` attribute to be compared by the address it points to, rather than by the value behind it. The `PartialOrd`, `Ord` and `Hash` traits have the same `ptr` parameter, which orders and hashes the address.
+
+```rust
+#[macro_use] extern crate educe;
+
+use std::rc::Rc;
+
+struct Node {
+    children: Vec<Rc<Node>>,
+}
+
+#[derive(Educe)]
+#[educe(PartialEq, Eq, Hash)]
+struct Edge {
+    #[educe(PartialEq(ptr), Hash(ptr))]
+    from: Rc<Node>,
+    #[educe(PartialEq(ptr), Hash(ptr))]
+    to: Rc<Node>,
+}
+```
+
+#### Unordered Collections
+
+A field which can be iterated by reference, such as a `Vec`, an array, a `HashSet` or a `HashMap`, can add a `#[educe(PartialEq(unordered))]` attribute to be compared as a multiset, ignoring the order of its elements. The `Hash` trait has the same `unordered` parameter, which combines the hashes of the elements regardless of their order. It also makes it possible to hash a `HashSet` or a `HashMap` field. The comparison counts every element in both fields, so it takes quadratic time in the number of elements.
+
+```rust
+#[macro_use] extern crate educe;
+
+use std::collections::HashSet;
+
+#[derive(Educe)]
+#[educe(PartialEq, Eq, Hash)]
+struct Group {
+    #[educe(PartialEq(unordered), Hash(unordered))]
+    members: Vec<u32>,
+    #[educe(PartialEq(unordered), Hash(unordered))]
+    tags: HashSet<String>,
+}
+```
+
+The elements are compared with each other one by one, so the comparison takes quadratic time and is better suited to small collections.
+
+#### Compare Only Variants
+
+An enum can add a `variant_only` parameter to compare only its variants, as if every field were ignored.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(PartialEq(variant_only))]
+enum Event {
+    Click { x: i32, y: i32 },
+    Key(char),
+}
+```
+
+#### Compare with Another Type
+
+A struct can add a `rhs` parameter to also implement `PartialEq` for another right-hand side type. The `rhs` parameter can be used several times. A tuple struct with only one field compares that field with the right-hand side. Other structs compare their fields with the fields of the same names in the right-hand side. The `rhs` parameter can not be used if a field also sets how it is compared, except with the `ignore` parameter.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(PartialEq(rhs = "&str", rhs = "String"))]
+struct Name(String);
+
+struct OtherVersion {
+    major: u32,
+    minor: u32,
+}
+
+#[derive(Educe)]
+#[educe(PartialEq(rhs = "OtherVersion"))]
+struct Version {
+    major: u32,
+    minor: u32,
+}
+
+assert!(Name(String::from("educe")) == "educe");
+```
+
+#### Union
+
+A union can implement the `PartialEq` trait only if the `union_tag` attribute (see the `Debug` section) or the `bytes` attribute is set. With `union_tag`, two unions are equal if their tags are equal and the values of their active fields are equal.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(PartialEq, union_tag = "Self::active_field")]
+union Union {
+    f1: u8,
+    f2: i8,
+}
+
+impl Union {
+    fn active_field(&self) -> &'static str {
+        "f1"
+    }
+}
+```
+
+A union whose fields are all padding-free primitive types or arrays of them, with the same size, can also be compared byte by byte with the `bytes` attribute. It can then implement the `Eq` trait as well.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(PartialEq(bytes), Eq)]
+union Union {
+    f1: [u8; 4],
+    f2: u32,
+}
+```
+
 ## Eq
 
 Use `#[derive(Educe)]` and `#[educe(Eq)]` to implement the `Eq` trait for a struct, an enum or a union.
@@ -473,7 +883,7 @@ enum Enum<T, K> {
 
 ## PartialOrd
 
-Use `#[derive(Educe)]` and `#[educe(PartialOrd)]` to implement the `PartialOrd` trait for a struct or an enum. It supports to ignore some fields, or set a trait and/or a method to replace the `PartialOrd` trait used by default. The rank of variants and fields can also be modified.
+Use `#[derive(Educe)]` and `#[educe(PartialOrd)]` to implement the `PartialOrd` trait for a struct, an enum, or a union. It supports to ignore some fields, or set a trait and/or a method to replace the `PartialOrd` trait used by default. The rank of variants and fields can also be modified.
 
 #### Basic Usage
 
@@ -665,9 +1075,166 @@ enum Enum {
 }
 ```
 
+The `comparison_value` of a variant can also be an integer constant expression in a string, such as `rank = "PRIORITY_HIGH"`, or `rank = "discriminant"` to use the discriminant of the variant. A variant which is not a unit variant needs an explicit discriminant for `"discriminant"`. Since these values are only known at compile time, reusing one of them is reported as a compile error.
+
+```rust
+#[macro_use] extern crate educe;
+
+const PRIORITY_HIGH: u8 = 2;
+const PRIORITY_LOW: u8 = 1;
+
+#[derive(Educe)]
+#[educe(PartialEq, PartialOrd)]
+enum Enum {
+    #[educe(PartialOrd(rank = "PRIORITY_HIGH"))]
+    High,
+    #[educe(PartialOrd(rank = "PRIORITY_LOW"))]
+    Low,
+    #[educe(PartialOrd(rank = "discriminant"))]
+    Urgent = 3,
+}
+```
+
+#### Reverse the Ordering
+
+A field can add a `#[educe(PartialOrd(reverse))]` attribute to be compared in descending order. The attribute can also be added to a variant to reverse the comparison of all its fields, or to the type to reverse the whole ordering, including the order of variants. A `reverse` attribute on a field flips the ordering again inside a reversed variant or type, and it works together with the `rank` attribute.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(PartialEq, PartialOrd)]
+struct Struct {
+    f1: u8,
+    #[educe(PartialOrd(reverse))]
+    f2: u8,
+}
+
+#[derive(Educe)]
+#[educe(PartialEq, PartialOrd(reverse))]
+enum Enum {
+    Unit,
+    #[educe(PartialOrd(reverse))]
+    Tuple(u8, u8),
+}
+```
+
+#### The Position of `None`
+
+An `Option` field can add a `#[educe(PartialOrd(none = "last"))]` attribute to place `None` after every `Some`, or a `#[educe(PartialOrd(none = "first"))]` attribute to place it before every `Some` even if the field is reversed. The inner values are still compared normally, or by the `method` and the `trait` of the field, which then receive the inner values.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(PartialEq, PartialOrd)]
+struct Struct {
+    #[educe(PartialOrd(none = "last"))]
+    f1: Option<u8>,
+    f2: u8,
+}
+```
+
+#### Total Ordering for Floats
+
+An `f32` or `f64` field can add a `#[educe(PartialOrd(total))]` attribute to be compared by `total_cmp`, so that `partial_cmp` never returns `None` for it. `-0.0` is treated as `0.0` and every `NaN` is greater than any other value, which is consistent with `PartialEq(total)` (see the `PartialEq` section).
+
+#### Compare by a Key
+
+A field can add a `#[educe(PartialOrd(by = "expression"))]` attribute to be ordered by the key which `expression` returns for a reference to the field. It can be combined with the `reverse` parameter, but not with the `none`, `total`, `method` or `trait` parameters.
+
+```rust
+#[macro_use] extern crate educe;
+
+fn len(s: &String) -> usize {
+    s.len()
+}
+
+#[derive(Educe)]
+#[educe(PartialEq, PartialOrd)]
+struct Word {
+    #[educe(PartialEq(by = "len"), PartialOrd(by = "len"))]
+    text: String,
+}
+```
+
+#### Compare by Identity
+
+A smart pointer, reference or raw pointer field can add a `#[educe(PartialOrd(ptr))]` attribute to be ordered by the address it points to (see the `PartialEq` section).
+
+#### Compare Only Variants
+
+An enum can add a `variant_only` parameter to order only its variants, by their ranks (see the `PartialEq` section).
+
+#### Incomparable Variants
+
+An enum can add a `#[educe(PartialOrd(cross_variant = "none"))]` attribute, so that comparing two values of different variants returns `None`. A variant can also set the `cross_variant` parameter, to `"none"` or `"rank"`, to override the setting of the enum. Two different variants are ordered by their ranks only when both of them use `"rank"`. Because `Ord` must order every pair of values, `"none"` cannot be used if the `Ord` trait is also implemented by Educe.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(PartialEq, PartialOrd)]
+enum Value {
+    Integer(i32),
+    Text(String),
+    #[educe(PartialOrd(cross_variant = "none"))]
+    Unknown,
+}
+
+assert!(Value::Integer(1) < Value::Text(String::new()));
+assert_eq!(None, Value::Integer(1).partial_cmp(&Value::Unknown));
+```
+
+#### Compare with Another Type
+
+A struct can add a `rhs` parameter to also implement `PartialOrd` for another right-hand side type, in the same way as `PartialEq` (see the `PartialEq` section). The `rhs` parameter can not be used if a field also sets how it is compared, except with the `ignore`, `rank` and `reverse` parameters. The `PartialEq` implementation for the same right-hand side type is still needed.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(PartialEq(rhs = "u32"), PartialOrd(rhs = "u32"))]
+struct Version(u32);
+
+assert!(Version(1) < 2);
+```
+
+#### Compare via `Ord`
+
+The `#[educe(PartialOrd(via_ord))]` attribute can be used to implement `PartialOrd` by calling `Ord::cmp`, so that `partial_cmp` always agrees with `cmp`. It can not be used with other `PartialOrd` parameters of the type, its variants or its fields.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(PartialEq, Eq, PartialOrd(via_ord), Ord)]
+struct Struct {
+    #[educe(Ord(rank = 1))]
+    f1: u8,
+    #[educe(Ord(rank = 0))]
+    f2: u8,
+}
+```
+
+#### Union
+
+A union can implement the `PartialOrd` trait only with the `bytes` attribute, which compares the raw bytes of the union lexicographically. It has the same requirements as `PartialEq(bytes)`.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(PartialEq(bytes), PartialOrd(bytes))]
+union Union {
+    f1: [u8; 4],
+    f2: u32,
+}
+```
+
 ## Ord
 
-Use `#[derive(Educe)]` and `#[educe(Ord)]` to implement the `Ord` trait for a struct or an enum. It supports to ignore some fields, or set a trait and/or a method to replace the `Ord` trait used by default. The rank of variants and fields can also be modified.
+Use `#[derive(Educe)]` and `#[educe(Ord)]` to implement the `Ord` trait for a struct, an enum, or a union. It supports to ignore some fields, or set a trait and/or a method to replace the `Ord` trait used by default. The rank of variants and fields can also be modified.
 
 #### Basic Usage
 
@@ -859,9 +1426,115 @@ enum Enum {
 }
 ```
 
+The `comparison_value` of a variant can also be an integer constant expression in a string, such as `rank = "PRIORITY_HIGH"`, or `rank = "discriminant"` to use the discriminant of the variant. A variant which is not a unit variant needs an explicit discriminant for `"discriminant"`. Since these values are only known at compile time, reusing one of them is reported as a compile error.
+
+```rust
+#[macro_use] extern crate educe;
+
+const PRIORITY_HIGH: u8 = 2;
+const PRIORITY_LOW: u8 = 1;
+
+#[derive(Educe)]
+#[educe(PartialEq, Eq, PartialOrd, Ord)]
+enum Enum {
+    #[educe(Ord(rank = "PRIORITY_HIGH"))]
+    High,
+    #[educe(Ord(rank = "PRIORITY_LOW"))]
+    Low,
+    #[educe(Ord(rank = "discriminant"))]
+    Urgent = 3,
+}
+```
+
+#### Reverse the Ordering
+
+A field can add a `#[educe(Ord(reverse))]` attribute to be compared in descending order. The attribute can also be added to a variant to reverse the comparison of all its fields, or to the type to reverse the whole ordering, including the order of variants. A `reverse` attribute on a field flips the ordering again inside a reversed variant or type, and it works together with the `rank` attribute.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(PartialEq, Eq, PartialOrd, Ord)]
+struct Struct {
+    f1: u8,
+    #[educe(Ord(reverse))]
+    f2: u8,
+}
+
+#[derive(Educe)]
+#[educe(PartialEq, Eq, PartialOrd, Ord(reverse))]
+enum Enum {
+    Unit,
+    #[educe(Ord(reverse))]
+    Tuple(u8, u8),
+}
+```
+
+#### The Position of `None`
+
+An `Option` field can add a `#[educe(Ord(none = "last"))]` attribute to place `None` after every `Some`, or a `#[educe(Ord(none = "first"))]` attribute to place it before every `Some` even if the field is reversed. The inner values are still compared normally, or by the `method` and the `trait` of the field, which then receive the inner values.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(PartialEq, Eq, PartialOrd, Ord)]
+struct Struct {
+    #[educe(Ord(none = "last"))]
+    f1: Option<u8>,
+    f2: u8,
+}
+```
+
+#### Total Ordering for Floats
+
+An `f32` or `f64` field can add a `#[educe(Ord(total))]` attribute to be compared by `total_cmp`. `-0.0` is treated as `0.0` and every `NaN` is greater than any other value, which is consistent with `PartialEq(total)` (see the `PartialEq` section).
+
+#### Compare by a Key
+
+A field can add a `#[educe(Ord(by = "expression"))]` attribute to be ordered by the key which `expression` returns for a reference to the field. It can be combined with the `reverse` parameter, but not with the `none`, `total`, `method` or `trait` parameters.
+
+```rust
+#[macro_use] extern crate educe;
+
+fn len(s: &String) -> usize {
+    s.len()
+}
+
+#[derive(Educe)]
+#[educe(PartialEq, Eq, PartialOrd, Ord)]
+struct Word {
+    #[educe(PartialEq(by = "len"), PartialOrd(by = "len"), Ord(by = "len"))]
+    text: String,
+}
+```
+
+#### Compare by Identity
+
+A smart pointer, reference or raw pointer field can add a `#[educe(Ord(ptr))]` attribute to be ordered by the address it points to (see the `PartialEq` section).
+
+#### Compare Only Variants
+
+An enum can add a `variant_only` parameter to order only its variants, by their ranks (see the `PartialEq` section).
+
+#### Union
+
+A union can implement the `Ord` trait only with the `bytes` attribute, which compares the raw bytes of the union lexicographically. It has the same requirements as `PartialEq(bytes)`.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(PartialEq(bytes), Eq, PartialOrd(bytes), Ord(bytes))]
+union Union {
+    f1: [u8; 4],
+    f2: u32,
+}
+```
+
 ## Hash
 
-Use `#[derive(Educe)]` and `#[educe(Hash)]` to implement the `Hash` trait for a struct or an enum. It supports to ignore some fields, or set a trait and/or a method to replace the `Hash` trait used by default.
+Use `#[derive(Educe)]` and `#[educe(Hash)]` to implement the `Hash` trait for a struct, an enum, or a union. It supports to ignore some fields, or set a trait and/or a method to replace the `Hash` trait used by default.
 
 #### Basic Usage
 
@@ -1005,6 +1678,78 @@ enum Enum<T, K> {
 }
 ```
 
+#### Hash Floats
+
+An `f32` or `f64` field can add a `#[educe(Hash(total))]` attribute to be hashed by its bits, after every `NaN` is turned into the same `NaN` and `-0.0` is turned into `0.0`. It matches `PartialEq(total)` (see the `PartialEq` section).
+
+#### Hash by a Key
+
+A field can add a `#[educe(Hash(by = "expression"))]` attribute to hash the key which `expression` returns for a reference to the field, instead of the field itself. Use the same `expression` as the `by` parameter of `PartialEq` (see the `PartialEq` section).
+
+#### Hash by Identity
+
+A smart pointer, reference or raw pointer field can add a `#[educe(Hash(ptr))]` attribute to hash the address it points to, which matches `PartialEq(ptr)`.
+
+#### Hash Unordered Collections
+
+A field which can be iterated by reference can add a `#[educe(Hash(unordered))]` attribute to be hashed regardless of the order of its elements, which matches `PartialEq(unordered)`. Each element is hashed on its own and the results are added up, so a `HashSet` or a `HashMap` field can be hashed too. The elements are hashed by SipHash with a key taken from the current state of the hasher, so the hashes of the elements are only as unpredictable as that state. Only the number of elements and the sum of their hashes are written to the hasher.
+
+#### Hash Only Variants
+
+An enum can add a `#[educe(Hash(variant_only))]` attribute to hash only its variants, which matches `PartialEq(variant_only)`.
+
+#### Stable Variant Keys
+
+By default, a variant is hashed by its position, so reordering or inserting variants changes the hash values. An enum can add a `#[educe(Hash(variant_key = "name"))]` attribute to hash the name of each variant instead, or a `#[educe(Hash(variant_key = "discriminant"))]` attribute to hash the discriminant of each variant as an `isize`. An enum which has non-unit variants needs explicit discriminants for `"discriminant"`.
+
+A variant can also add a `#[educe(Hash(variant_key = "key"))]` or `#[educe(Hash(variant_key = integer))]` attribute to hash that key instead, for example to keep the old name of a renamed variant.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(Hash(variant_key = "name"))]
+enum Event {
+    Click { x: i32, y: i32 },
+    #[educe(Hash(variant_key = "KeyPress"))]
+    Key(char),
+}
+```
+
+#### Union
+
+A union can implement the `Hash` trait only if the `union_tag` attribute (see the `Debug` section) or the `bytes` attribute is set. With `union_tag`, the name of the active field is hashed before the value of the field.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(Hash, union_tag = "Self::active_field")]
+union Union {
+    f1: u8,
+    f2: i8,
+}
+
+impl Union {
+    fn active_field(&self) -> &'static str {
+        "f1"
+    }
+}
+```
+
+The `bytes` attribute hashes the raw bytes of a union instead. It has the same requirements as `PartialEq(bytes)`.
+
+```rust
+#[macro_use] extern crate educe;
+
+#[derive(Educe)]
+#[educe(Hash(bytes))]
+union Union {
+    f1: [u8; 4],
+    f2: u32,
+}
+```
+
 ## Default
 
 Use `#[derive(Educe)]` and `#[educe(Default)]` to implement the `Default` trait for a struct, an enum, or a union. It supports to set the default value for your type directly, or set the default values for specific fields.
@@ -1135,6 +1880,22 @@ union Union {
 }
 ```
 
+If the default field of a union is a `ManuallyDrop<T>`, the literal is the value of `T` and it will be wrapped in `ManuallyDrop::new` automatically. An expression is used as it is, so it needs to create the `ManuallyDrop<T>` itself.
+
+```rust
+#[macro_use] extern crate educe;
+
+use std::mem::ManuallyDrop;
+
+#[derive(Educe)]
+#[educe(Default)]
+union Union {
+    f1: u8,
+    #[educe(Default = "Hi")]
+    f2: ManuallyDrop<String>,
+}
+```
+
 #### Generic Parameters Bound to the `Default` Trait or Others
 
 The `#[educe(Default(bound))]` attribute can be used to add the `Default` trait bound to all generaic parameters for the `Default` implementation.
@@ -1326,6 +2087,42 @@ union Union {
 }
 ```
 
+If the `union_tag` attribute is set (see the `Debug` section), the union does not need to implement the `Copy` trait and only its active field is cloned. The `bound` attribute can be used in this case.
+
+```rust
+#[macro_use] extern crate educe;
+
+use std::mem::ManuallyDrop;
+
+#[derive(Educe)]
+#[educe(Clone, union_tag = "Self::active_field")]
+union Union {
+    f1: u8,
+    f2: ManuallyDrop<String>,
+}
+
+impl Union {
+    fn active_field(&self) -> &'static str {
+        "f2"
+    }
+}
+```
+
+A union which is always initialized through one known field can also mark that field with `#[educe(Clone)]`. The union is then cloned by cloning the marked field, so the union does not need to implement the `Copy` trait. It is your responsibility to make sure the marked field is the active one.
+
+```rust
+#[macro_use] extern crate educe;
+
+use std::mem::ManuallyDrop;
+
+#[derive(Educe)]
+#[educe(Clone)]
+union Union {
+    #[educe(Clone)]
+    f1: ManuallyDrop<String>,
+}
+```
+
 ## Copy
 
 Use `#[derive(Educe)]` and `#[educe(Copy)]` to implement the `Copy` trait for a struct, an enum, or a union.
@@ -1529,7 +2326,7 @@ use std::collections::BTreeMap;
 
 use proc_macro2::TokenStream;
 use quote::ToTokens;
-use syn::{DeriveInput, Meta, NestedMeta};
+use syn::{Data, DeriveInput, Meta, NestedMeta};
 
 use support_traits::Trait;
 use trait_handlers::TraitHandler;
@@ -1537,6 +2334,7 @@ use trait_handlers::TraitHandler;
 fn derive_input_handler(ast: DeriveInput) -> TokenStream {
     let mut tokens = TokenStream::new();
     let mut trait_meta_map: BTreeMap<Trait, Meta> = BTreeMap::new();
+    let mut union_tag_is_set = false;
 
     for attr in ast.attrs.iter() {
         if let Some(attr_meta_name) = attr.path.get_ident() {
@@ -1550,6 +2348,25 @@ fn derive_input_handler(ast: DeriveInput) -> TokenStream {
                                 NestedMeta::Meta(meta) => {
                                     let meta_name = meta.path().into_token_stream().to_string();
 
+                                    if meta_name == "union_tag" {
+                                        if union_tag_is_set {
+                                            panic::reset_parameter(meta_name.as_str());
+                                        }
+
+                                        union_tag_is_set = true;
+
+                                        if let Data::Union(_) = &ast.data {
+                                        } else {
+                                            panic::union_tag_not_union();
+                                        }
+
+                                        if trait_handlers::get_union_tag(&meta).is_none() {
+                                            panic::union_tag_incorrect_format();
+                                        }
+
+                                        continue;
+                                    }
+
                                     let t = Trait::from_str(meta_name);
 
                                     if trait_meta_map.contains_key(&t) {
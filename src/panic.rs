@@ -63,6 +63,69 @@ pub fn set_expression_bound() -> ! {
     panic!("You don't need to set the expression and the bound at the same time.")
 }
 
+#[inline]
+pub fn set_max_items_max_len() -> ! {
+    panic!("The max_items parameter and the max_len parameter can not be set at the same time.")
+}
+
+#[inline]
+pub fn set_sorted_limit() -> ! {
+    panic!(
+        "The sorted parameter can not be set with the max_items or max_len parameter at the same \
+         time."
+    )
+}
+
+#[inline]
+pub fn set_limit_impl() -> ! {
+    panic!(
+        "The max_items, max_len or sorted parameter can not be set with the method or trait \
+         parameter at the same time."
+    )
+}
+
+#[inline]
+pub fn flatten_field_with_format() -> ! {
+    panic!(
+        "A flattened field can not be set with the name, method, trait, max_items, max_len or \
+         sorted parameter."
+    )
+}
+
+#[inline]
+pub fn set_name_style() -> ! {
+    panic!("The name parameter and the style parameter can not be set at the same time.")
+}
+
+#[inline]
+pub fn discriminant_style_non_unit_variant() -> ! {
+    panic!("The discriminant style can only be used for an enum whose variants are all unit variants.")
+}
+
+#[inline]
+pub fn with_generics_without_name() -> ! {
+    panic!("The with_generics parameter needs the name of the type to be printed.")
+}
+
+#[inline]
+pub fn max_depth_out_of_range(max_depth: usize) -> ! {
+    panic!("The max_depth parameter needs to be between 1 and 64, but it is {}.", max_depth)
+}
+
+#[inline]
+pub fn set_opaque_unsafe_bytes() -> ! {
+    panic!("The opaque parameter and the unsafe_bytes parameter can not be set at the same time.")
+}
+
+#[inline]
+pub fn union_field_may_have_padding(field: &str) -> ! {
+    panic!(
+        "The type of the field `{}` is not known to be free of padding bytes, so the union can \
+         not be treated as bytes safely.",
+        field
+    )
+}
+
 #[inline]
 pub fn no_default_field() -> ! {
     panic!("There is no field set as default.")
@@ -170,11 +233,106 @@ pub fn unit_variant_need_name() -> ! {
     panic!("A unit variant which doesn't use an enum name needs to have a name.")
 }
 
+#[inline]
+pub fn set_total_impl() -> ! {
+    panic!("The total parameter can not be set with the method or trait parameter at the same time.")
+}
+
+#[inline]
+pub fn rank_without_discriminant(variant: &str) -> ! {
+    panic!(
+        "The variant `{}` needs an explicit discriminant to be ranked by its discriminant.",
+        variant
+    )
+}
+
+#[inline]
+pub fn variant_key_without_discriminant(variant: &str) -> ! {
+    panic!(
+        "The variant `{}` needs an explicit discriminant to be hashed by its discriminant.",
+        variant
+    )
+}
+
+#[inline]
+pub fn set_via_ord_with_settings() -> ! {
+    panic!(
+        "The via_ord parameter can not be set with other PartialOrd parameters of the type, its \
+         variants or its fields at the same time."
+    )
+}
+
+#[inline]
+pub fn set_rhs_with_field_settings() -> ! {
+    panic!(
+        "The rhs parameter can not be set when a field also sets how it is compared, except by \
+         the ignore parameter, or the rank or reverse parameter of PartialOrd."
+    )
+}
+
+#[inline]
+pub fn set_unordered_impl() -> ! {
+    panic!(
+        "The unordered parameter can not be set with another parameter which changes how the \
+         field is compared or hashed at the same time."
+    )
+}
+
+#[inline]
+pub fn set_ptr_impl() -> ! {
+    panic!(
+        "The ptr parameter can not be set with another parameter which changes how the field is \
+         compared or hashed at the same time."
+    )
+}
+
+#[inline]
+pub fn set_by_impl() -> ! {
+    panic!(
+        "The by parameter can not be set with another parameter which changes how the field is \
+         compared or hashed at the same time."
+    )
+}
+
+#[inline]
+pub fn set_approx_impl() -> ! {
+    panic!(
+        "The approx parameter can not be set with the total, method or trait parameter at the \
+         same time."
+    )
+}
+
+#[inline]
+pub fn approx_with_eq_or_hash() -> ! {
+    panic!(
+        "The approx parameter can not be used when the Eq or Hash trait is also implemented, \
+         because approximate equality is not transitive."
+    )
+}
+
+#[inline]
+pub fn cross_variant_none_with_ord() -> ! {
+    panic!(
+        "The cross_variant parameter can not be set to \"none\" when the Ord trait is also \
+         implemented, because Ord must order every pair of values."
+    )
+}
+
+#[inline]
+pub fn total_non_float_field() -> ! {
+    panic!("The total parameter can only be used for a field of type f32 or f64.")
+}
+
 #[inline]
 pub fn ignore_ranked_field() -> ! {
     panic!("You can't ignore a ranked field.")
 }
 
+#[inline]
+pub fn ignore_reversed_field() -> ! {
+    panic!("You can't ignore a reversed field.")
+}
+
 #[inline]
 pub fn reuse_a_rank(rank: isize) -> ! {
     panic!("The rank `{}` is repeatedly used.", rank)
@@ -187,6 +345,42 @@ pub fn reuse_a_value(value: isize) -> ! {
 
 // TODO patterns
 
+#[inline]
+pub fn union_tag_not_union() -> ! {
+    panic!("The union_tag parameter can only be used for a union.")
+}
+
+#[inline]
+pub fn union_tag_incorrect_format() -> ! {
+    attribute_incorrect_format("union_tag", &[
+        stringify!(#[educe(union_tag = "path_to_function")]),
+        stringify!(#[educe(union_tag("path_to_function"))]),
+    ])
+}
+
+#[inline]
+pub fn set_union_tag_union_format() -> ! {
+    panic!(
+        "The opaque or unsafe_bytes parameter can not be used for a union with the union_tag \
+         parameter."
+    )
+}
+
+#[inline]
+pub fn set_union_tag_bytes() -> ! {
+    panic!("The bytes parameter can not be used for a union with the union_tag parameter.")
+}
+
+#[inline]
+pub fn multiple_clone_fields() -> ! {
+    panic!("Multiple fields are set to be cloned.")
+}
+
+#[inline]
+pub fn set_union_tag_clone_field() -> ! {
+    panic!("A field to be cloned can not be set for a union with the union_tag parameter.")
+}
+
 #[inline]
 pub fn educe_format_incorrect() -> ! {
     attribute_incorrect_format("educe", &[stringify!(#[educe(Trait1, Trait2, ..., TraitN)])])
@@ -56,6 +56,7 @@ impl TraitHandler for CloneEnumHandler {
 
                         for field in fields.named.iter() {
                             let field_attribute = FieldAttributeBuilder {
+                                enable_flag: false,
                                 enable_impl: true,
                             }
                             .from_attributes(&field.attrs, traits);
@@ -75,6 +76,7 @@ impl TraitHandler for CloneEnumHandler {
                         // TODO Tuple
                         for (index, field) in fields.unnamed.iter().enumerate() {
                             let field_attribute = FieldAttributeBuilder {
+                                enable_flag: false,
                                 enable_impl: true,
                             }
                             .from_attributes(&field.attrs, traits);
@@ -37,6 +37,7 @@ impl TraitHandler for CloneStructHandler {
 
             for (index, field) in data.fields.iter().enumerate() {
                 let field_attribute = FieldAttributeBuilder {
+                    enable_flag: false,
                     enable_impl: true,
                 }
                 .from_attributes(&field.attrs, traits);
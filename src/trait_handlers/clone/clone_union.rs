@@ -1,8 +1,11 @@
-use super::super::TraitHandler;
+use std::str::FromStr;
+
+use super::super::{find_union_tag, TraitHandler};
 use super::models::{FieldAttributeBuilder, TypeAttributeBuilder};
 
+use crate::panic;
 use crate::proc_macro2::TokenStream;
-use crate::syn::{Data, DeriveInput, Meta};
+use crate::syn::{Data, DeriveInput, Generics, Meta};
 use crate::Trait;
 
 pub struct CloneUnionHandler;
@@ -14,30 +17,100 @@ impl TraitHandler for CloneUnionHandler {
         traits: &[Trait],
         meta: &Meta,
     ) {
-        let _ = TypeAttributeBuilder {
-            enable_flag: true,
-            enable_bound: false,
-        }
-        .from_clone_meta(meta);
+        let union_tag = find_union_tag(ast);
+
+        let mut clone_field = None;
 
         if let Data::Union(data) = &ast.data {
             for field in data.fields.named.iter() {
-                let _ = FieldAttributeBuilder {
+                let field_attribute = FieldAttributeBuilder {
+                    enable_flag: true,
                     enable_impl: false,
                 }
                 .from_attributes(&field.attrs, traits);
+
+                if field_attribute.flag {
+                    if clone_field.is_some() {
+                        panic::multiple_clone_fields();
+                    }
+
+                    clone_field = Some(field.ident.as_ref().unwrap().to_string());
+                }
+            }
+        }
+
+        if union_tag.is_some() && clone_field.is_some() {
+            panic::set_union_tag_clone_field();
+        }
+
+        let type_attribute = TypeAttributeBuilder {
+            enable_flag: true,
+            enable_bound: union_tag.is_some() || clone_field.is_some(),
+        }
+        .from_clone_meta(meta);
+
+        let bound = type_attribute
+            .bound
+            .into_punctuated_where_predicates_by_generic_parameters(&ast.generics.params);
+
+        let mut clone_tokens = TokenStream::new();
+
+        if let Data::Union(data) = &ast.data {
+            match union_tag {
+                Some(union_tag) => {
+                    let mut match_tokens =
+                        format!("match {union_tag}(self) {{", union_tag = union_tag);
+
+                    for field in data.fields.named.iter() {
+                        let field_name = field.ident.as_ref().unwrap().to_string();
+
+                        match_tokens.push_str(&format!(
+                            "{field_name:?} => Self {{ {field_name}: unsafe {{ core::clone::Clone::clone(&self.{field_name}) }} }},",
+                            field_name = field_name
+                        ));
+                    }
+
+                    match_tokens.push_str(
+                        "_ => core::panic!(\"The union tag returned an unknown field name.\"), }",
+                    );
+
+                    clone_tokens.extend(TokenStream::from_str(&match_tokens).unwrap());
+                }
+                None => {
+                    match clone_field {
+                        Some(field_name) => {
+                            let statement = format!(
+                                "Self {{ {field_name}: unsafe {{ core::clone::Clone::clone(&self.{field_name}) }} }}",
+                                field_name = field_name
+                            );
+
+                            clone_tokens.extend(TokenStream::from_str(&statement).unwrap());
+                        }
+                        None => {
+                            clone_tokens.extend(quote!(*self));
+                        }
+                    }
+                }
             }
         }
 
         let ident = &ast.ident;
 
-        let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+        let mut generics_cloned: Generics = ast.generics.clone();
+
+        let where_clause = generics_cloned.make_where_clause();
+
+        for where_predicate in bound {
+            where_clause.predicates.push(where_predicate);
+        }
+
+        let (impl_generics, ty_generics, where_clause) = generics_cloned.split_for_impl();
 
         let compare_impl = quote! {
             impl #impl_generics core::clone::Clone for #ident #ty_generics #where_clause {
                 #[inline]
                 fn clone(&self) -> Self {
-                    *self
+                    #clone_tokens
                 }
             }
         };
@@ -7,23 +7,30 @@ use crate::Trait;
 
 #[derive(Debug, Clone)]
 pub struct FieldAttribute {
+    pub flag: bool,
     pub clone_method: Option<String>,
     pub clone_trait: Option<String>,
 }
 
 #[derive(Debug, Clone)]
 pub struct FieldAttributeBuilder {
+    pub enable_flag: bool,
     pub enable_impl: bool,
 }
 
 impl FieldAttributeBuilder {
     #[allow(clippy::cognitive_complexity)]
     pub fn from_clone_meta(&self, meta: &Meta) -> FieldAttribute {
+        let mut flag = false;
         let mut clone_method = None;
         let mut clone_trait = None;
 
         let correct_usage_for_clone_attribute = {
-            let usage = vec![];
+            let mut usage = vec![];
+
+            if self.enable_flag {
+                usage.push(stringify!(#[educe(Clone)]));
+            }
 
             usage
         };
@@ -223,7 +230,16 @@ impl FieldAttributeBuilder {
                     }
                 }
             }
-            _ => panic::attribute_incorrect_format("Clone", &correct_usage_for_clone_attribute),
+            Meta::NameValue(_) => {
+                panic::attribute_incorrect_format("Clone", &correct_usage_for_clone_attribute)
+            }
+            Meta::Path(_) => {
+                if !self.enable_flag {
+                    panic::attribute_incorrect_format("Clone", &correct_usage_for_clone_attribute);
+                }
+
+                flag = true;
+            }
         }
 
         if clone_trait.is_some() && clone_method.is_none() {
@@ -231,6 +247,7 @@ impl FieldAttributeBuilder {
         }
 
         FieldAttribute {
+            flag,
             clone_method,
             clone_trait,
         }
@@ -277,6 +294,7 @@ impl FieldAttributeBuilder {
         }
 
         result.unwrap_or(FieldAttribute {
+            flag: false,
             clone_method: None,
             clone_trait: None,
         })
@@ -2,14 +2,19 @@ use std::fmt::Write;
 use std::str::FromStr;
 
 use super::super::TraitHandler;
+use super::{
+    create_flatten_statement, create_generics_name_prefix, create_limited_format_arg,
+    create_max_depth_body,
+};
 use super::models::{
     FieldAttributeBuilder, FieldAttributeName, TypeAttributeBuilder, TypeAttributeName,
+    TypeAttributeStyle, TypeAttributeWithGenerics,
 };
 
 use crate::panic;
 use crate::proc_macro2::TokenStream;
 use crate::quote::ToTokens;
-use crate::syn::{Data, DeriveInput, Fields, Generics, Meta};
+use crate::syn::{Data, DeriveInput, Fields, Generics, Meta, NestedMeta};
 use crate::Trait;
 
 pub struct DebugEnumHandler;
@@ -29,18 +34,63 @@ impl TraitHandler for DebugEnumHandler {
             named_field: false,
             enable_named_field: false,
             enable_bound: true,
+            non_exhaustive: false,
+            enable_non_exhaustive: true,
+            enable_rename_all: true,
+            enable_style: true,
+            enable_with_generics: true,
+            enable_max_depth: true,
+            enable_union_format: false,
         }
         .from_debug_meta(meta);
 
         let enum_name = ast.ident.to_string();
 
-        let name = type_attribute.name.into_string_by_ident(&ast.ident);
+        let non_exhaustive = type_attribute.non_exhaustive;
+
+        let rename_all = type_attribute.rename_all;
+
+        let style = type_attribute.style;
+
+        let mut name = match style {
+            TypeAttributeStyle::Qualified => enum_name.clone(),
+            TypeAttributeStyle::Variant => String::new(),
+            _ => type_attribute.name.into_string_by_ident(&ast.ident),
+        };
+
+        let discriminant_type = if style == TypeAttributeStyle::Discriminant {
+            if let Data::Enum(data) = &ast.data {
+                for variant in data.variants.iter() {
+                    if let Fields::Unit = &variant.fields {
+                    } else {
+                        panic::discriminant_style_non_unit_variant();
+                    }
+                }
+            }
+
+            find_repr_type(ast)
+        } else {
+            String::new()
+        };
 
         let bound = type_attribute
             .bound
             .into_punctuated_where_predicates_by_generic_parameters(&ast.generics.params);
 
         let mut builder_tokens = TokenStream::new();
+
+        if type_attribute.with_generics != TypeAttributeWithGenerics::None && name.is_empty() {
+            panic::with_generics_without_name();
+        }
+
+        if let Some(prefix) =
+            create_generics_name_prefix(&name, &ast.generics, type_attribute.with_generics)
+        {
+            builder_tokens.extend(TokenStream::from_str(&prefix).unwrap());
+
+            name = String::from(">");
+        }
+
         let mut has_variants = false;
 
         let mut match_tokens = String::from("match self {");
@@ -58,13 +108,29 @@ impl TraitHandler for DebugEnumHandler {
                     },
                     enable_named_field: true,
                     enable_bound: false,
+                    non_exhaustive,
+                    enable_non_exhaustive: true,
+                    enable_rename_all: true,
+                    enable_style: false,
+                    enable_with_generics: false,
+                    enable_max_depth: false,
+                    enable_union_format: false,
                 }
                 .from_attributes(&variant.attrs, traits);
 
-                let variant_name = type_attribute.name.into_string_by_ident(&variant.ident);
+                let is_custom_name = matches!(type_attribute.name, TypeAttributeName::Custom(_));
+
+                let variant_name = match type_attribute.name {
+                    TypeAttributeName::Default => rename_all.rename(&variant.ident.to_string()),
+                    name => name.into_string_by_ident(&variant.ident),
+                };
+
+                let field_rename_all = type_attribute.rename_all;
 
                 let named_field = type_attribute.named_field;
 
+                let non_exhaustive = type_attribute.non_exhaustive;
+
                 let variant_ident = variant.ident.to_string();
 
                 let name = combine_names(&name, variant_name);
@@ -72,17 +138,22 @@ impl TraitHandler for DebugEnumHandler {
                 match &variant.fields {
                     Fields::Unit => {
                         // TODO Unit
-                        if name.is_empty() {
-                            panic::unit_variant_need_name();
-                        }
+                        if style == TypeAttributeStyle::Discriminant && !is_custom_name {
+                            match_tokens.write_fmt(format_args!("{enum_name}::{variant_ident} => {{ core::fmt::Debug::fmt(&({enum_name}::{variant_ident} as {discriminant_type}), formatter) }}", enum_name = enum_name, variant_ident = variant_ident, discriminant_type = discriminant_type)).unwrap();
+                        } else {
+                            if name.is_empty() {
+                                panic::unit_variant_need_name();
+                            }
 
-                        match_tokens.write_fmt(format_args!("{enum_name}::{variant_ident} => {{ formatter.write_str({name:?}) }}", enum_name = enum_name, variant_ident = variant_ident, name = name)).unwrap();
+                            match_tokens.write_fmt(format_args!("{enum_name}::{variant_ident} => {{ formatter.write_str({name:?}) }}", enum_name = enum_name, variant_ident = variant_ident, name = name)).unwrap();
+                        }
 
                         has_variants = true;
                     }
                     Fields::Named(fields) => {
                         // TODO Struct
                         let mut has_fields = false;
+                        let mut has_ignored_fields = false;
 
                         let mut pattern_tokens = String::new();
                         let mut block_tokens = String::new();
@@ -115,6 +186,8 @@ impl TraitHandler for DebugEnumHandler {
                                     enable_name: true,
                                     enable_ignore: true,
                                     enable_impl: true,
+                                    enable_limit: true,
+                                    enable_flatten: true,
                                 }
                                 .from_attributes(&field.attrs, traits);
 
@@ -127,6 +200,7 @@ impl TraitHandler for DebugEnumHandler {
                                             field_name = field_name
                                         ))
                                         .unwrap();
+                                    has_ignored_fields = true;
                                     continue;
                                 }
 
@@ -134,8 +208,12 @@ impl TraitHandler for DebugEnumHandler {
 
                                 let format_trait = field_attribute.format_trait;
                                 let format_method = field_attribute.format_method;
+                                let max_items = field_attribute.max_items;
+                                let max_len = field_attribute.max_len;
+                                let sorted = field_attribute.sorted;
 
-                                let key = rename.unwrap_or_else(|| field_name.clone());
+                                let key =
+                                    rename.unwrap_or_else(|| field_rename_all.rename(&field_name));
 
                                 pattern_tokens
                                     .write_fmt(format_args!(
@@ -144,6 +222,20 @@ impl TraitHandler for DebugEnumHandler {
                                     ))
                                     .unwrap();
 
+                                if field_attribute.flatten {
+                                    let statement = if name.is_empty() {
+                                        create_flatten_statement(&field_name, "builder.entry(&RawString(key), value);")
+                                    } else {
+                                        create_flatten_statement(&field_name, "builder.field(key, value);")
+                                    };
+
+                                    block_tokens.push_str(&statement);
+
+                                    has_fields = true;
+
+                                    continue;
+                                }
+
                                 match format_trait {
                                     Some(format_trait) => {
                                         let format_method = format_method.unwrap();
@@ -211,14 +303,37 @@ impl TraitHandler for DebugEnumHandler {
                                                 block_tokens.push_str(&statement);
                                             }
                                             None => {
-                                                let statement = if name.is_empty() {
-                                                    format!("builder.entry(&RawString({key:?}), {field_name});", key = key, field_name = field_name)
-                                                } else {
-                                                    format!(
-                                                        "builder.field({key:?}, {field_name});",
-                                                        key = key,
-                                                        field_name = field_name
-                                                    )
+                                                let limited_arg = create_limited_format_arg(
+                                                    max_items,
+                                                    max_len,
+                                                    sorted,
+                                                    &field_name,
+                                                );
+
+                                                let statement = match limited_arg {
+                                                    Some(arg) => {
+                                                        block_tokens.push_str(&arg);
+
+                                                        if name.is_empty() {
+                                                            format!("builder.entry(&RawString({key:?}), &arg);", key = key)
+                                                        } else {
+                                                            format!(
+                                                                "builder.field({key:?}, &arg);",
+                                                                key = key
+                                                            )
+                                                        }
+                                                    }
+                                                    None => {
+                                                        if name.is_empty() {
+                                                            format!("builder.entry(&RawString({key:?}), {field_name});", key = key, field_name = field_name)
+                                                        } else {
+                                                            format!(
+                                                                "builder.field({key:?}, {field_name});",
+                                                                key = key,
+                                                                field_name = field_name
+                                                            )
+                                                        }
+                                                    }
                                                 };
 
                                                 block_tokens.push_str(&statement);
@@ -243,6 +358,8 @@ impl TraitHandler for DebugEnumHandler {
                                     enable_name: false,
                                     enable_ignore: true,
                                     enable_impl: true,
+                                    enable_limit: true,
+                                    enable_flatten: false,
                                 }
                                 .from_attributes(&field.attrs, traits);
 
@@ -255,11 +372,15 @@ impl TraitHandler for DebugEnumHandler {
                                             field_name = field_name
                                         ))
                                         .unwrap();
+                                    has_ignored_fields = true;
                                     continue;
                                 }
 
                                 let format_trait = field_attribute.format_trait;
                                 let format_method = field_attribute.format_method;
+                                let max_items = field_attribute.max_items;
+                                let max_len = field_attribute.max_len;
+                                let sorted = field_attribute.sorted;
 
                                 pattern_tokens
                                     .write_fmt(format_args!(
@@ -314,11 +435,27 @@ impl TraitHandler for DebugEnumHandler {
                                                 block_tokens.push_str("builder.field(&arg);");
                                             }
                                             None => {
-                                                let statement = format!(
-                                                    "builder.field({field_name});",
-                                                    field_name = field_name
+                                                let limited_arg = create_limited_format_arg(
+                                                    max_items,
+                                                    max_len,
+                                                    sorted,
+                                                    &field_name,
                                                 );
 
+                                                let statement = match limited_arg {
+                                                    Some(arg) => {
+                                                        block_tokens.push_str(&arg);
+
+                                                        String::from("builder.field(&arg);")
+                                                    }
+                                                    None => {
+                                                        format!(
+                                                            "builder.field({field_name});",
+                                                            field_name = field_name
+                                                        )
+                                                    }
+                                                };
+
                                                 block_tokens.push_str(&statement);
                                             }
                                         }
@@ -333,7 +470,11 @@ impl TraitHandler for DebugEnumHandler {
                             panic::unit_struct_need_name();
                         }
 
-                        block_tokens.push_str("return builder.finish();");
+                        if non_exhaustive && has_ignored_fields {
+                            block_tokens.push_str("return builder.finish_non_exhaustive();");
+                        } else {
+                            block_tokens.push_str("return builder.finish();");
+                        }
 
                         match_tokens.write_fmt(format_args!("{enum_name}::{variant_ident}{{ {pattern_tokens} }} => {{ {block_tokens} }}", enum_name = enum_name, variant_ident = variant_ident, pattern_tokens = pattern_tokens, block_tokens = block_tokens)).unwrap();
 
@@ -342,6 +483,7 @@ impl TraitHandler for DebugEnumHandler {
                     Fields::Unnamed(fields) => {
                         // TODO Tuple
                         let mut has_fields = false;
+                        let mut has_ignored_fields = false;
 
                         let mut pattern_tokens = String::new();
                         let mut block_tokens = String::new();
@@ -374,11 +516,14 @@ impl TraitHandler for DebugEnumHandler {
                                     enable_name: true,
                                     enable_ignore: true,
                                     enable_impl: true,
+                                    enable_limit: true,
+                                    enable_flatten: true,
                                 }
                                 .from_attributes(&field.attrs, traits);
 
                                 if field_attribute.ignore {
                                     pattern_tokens.push_str("_,");
+                                    has_ignored_fields = true;
                                     continue;
                                 }
 
@@ -386,6 +531,9 @@ impl TraitHandler for DebugEnumHandler {
 
                                 let format_trait = field_attribute.format_trait;
                                 let format_method = field_attribute.format_method;
+                                let max_items = field_attribute.max_items;
+                                let max_len = field_attribute.max_len;
+                                let sorted = field_attribute.sorted;
 
                                 let (key, field_name) = match rename {
                                     Some(rename) => (rename, format!("{}", index)),
@@ -399,6 +547,20 @@ impl TraitHandler for DebugEnumHandler {
                                     ))
                                     .unwrap();
 
+                                if field_attribute.flatten {
+                                    let statement = if name.is_empty() {
+                                        create_flatten_statement(&format!("_{}", field_name), "builder.entry(&RawString(key), value);")
+                                    } else {
+                                        create_flatten_statement(&format!("_{}", field_name), "builder.field(key, value);")
+                                    };
+
+                                    block_tokens.push_str(&statement);
+
+                                    has_fields = true;
+
+                                    continue;
+                                }
+
                                 match format_trait {
                                     Some(format_trait) => {
                                         let format_method = format_method.unwrap();
@@ -466,14 +628,37 @@ impl TraitHandler for DebugEnumHandler {
                                                 block_tokens.push_str(&statement);
                                             }
                                             None => {
-                                                let statement = if name.is_empty() {
-                                                    format!("builder.entry(&RawString({key:?}), {field_name});", key = key, field_name = field_name)
-                                                } else {
-                                                    format!(
-                                                        "builder.field({key:?}, _{field_name});",
-                                                        key = key,
-                                                        field_name = field_name
-                                                    )
+                                                let limited_arg = create_limited_format_arg(
+                                                    max_items,
+                                                    max_len,
+                                                    sorted,
+                                                    &format!("_{field_name}", field_name = field_name),
+                                                );
+
+                                                let statement = match limited_arg {
+                                                    Some(arg) => {
+                                                        block_tokens.push_str(&arg);
+
+                                                        if name.is_empty() {
+                                                            format!("builder.entry(&RawString({key:?}), &arg);", key = key)
+                                                        } else {
+                                                            format!(
+                                                                "builder.field({key:?}, &arg);",
+                                                                key = key
+                                                            )
+                                                        }
+                                                    }
+                                                    None => {
+                                                        if name.is_empty() {
+                                                            format!("builder.entry(&RawString({key:?}), {field_name});", key = key, field_name = field_name)
+                                                        } else {
+                                                            format!(
+                                                                "builder.field({key:?}, _{field_name});",
+                                                                key = key,
+                                                                field_name = field_name
+                                                            )
+                                                        }
+                                                    }
                                                 };
 
                                                 block_tokens.push_str(&statement);
@@ -498,16 +683,22 @@ impl TraitHandler for DebugEnumHandler {
                                     enable_name: false,
                                     enable_ignore: true,
                                     enable_impl: true,
+                                    enable_limit: true,
+                                    enable_flatten: false,
                                 }
                                 .from_attributes(&field.attrs, traits);
 
                                 if field_attribute.ignore {
                                     pattern_tokens.push_str("_,");
+                                    has_ignored_fields = true;
                                     continue;
                                 }
 
                                 let format_trait = field_attribute.format_trait;
                                 let format_method = field_attribute.format_method;
+                                let max_items = field_attribute.max_items;
+                                let max_len = field_attribute.max_len;
+                                let sorted = field_attribute.sorted;
 
                                 let field_name = format!("{}", index);
 
@@ -564,11 +755,27 @@ impl TraitHandler for DebugEnumHandler {
                                                 block_tokens.push_str("builder.field(&arg);");
                                             }
                                             None => {
-                                                let statement = format!(
-                                                    "builder.field(_{field_name});",
-                                                    field_name = field_name
+                                                let limited_arg = create_limited_format_arg(
+                                                    max_items,
+                                                    max_len,
+                                                    sorted,
+                                                    &format!("_{field_name}", field_name = field_name),
                                                 );
 
+                                                let statement = match limited_arg {
+                                                    Some(arg) => {
+                                                        block_tokens.push_str(&arg);
+
+                                                        String::from("builder.field(&arg);")
+                                                    }
+                                                    None => {
+                                                        format!(
+                                                            "builder.field(_{field_name});",
+                                                            field_name = field_name
+                                                        )
+                                                    }
+                                                };
+
                                                 block_tokens.push_str(&statement);
                                             }
                                         }
@@ -583,7 +790,11 @@ impl TraitHandler for DebugEnumHandler {
                             panic::unit_struct_need_name();
                         }
 
-                        block_tokens.push_str("return builder.finish();");
+                        if non_exhaustive && has_ignored_fields {
+                            block_tokens.push_str("return builder.finish_non_exhaustive();");
+                        } else {
+                            block_tokens.push_str("return builder.finish();");
+                        }
 
                         match_tokens.write_fmt(format_args!("{enum_name}::{variant_ident}( {pattern_tokens} ) => {{ {block_tokens} }}", enum_name = enum_name, variant_ident = variant_ident, pattern_tokens = pattern_tokens, block_tokens = block_tokens)).unwrap();
 
@@ -613,12 +824,14 @@ impl TraitHandler for DebugEnumHandler {
 
         let (impl_generics, ty_generics, where_clause) = generics_cloned.split_for_impl();
 
+        let fmt_tokens = create_max_depth_body(type_attribute.max_depth, builder_tokens);
+
         let debug_impl = quote! {
             impl #impl_generics core::fmt::Debug for #ident #ty_generics #where_clause {
                 #[inline]
                 #[allow(clippy::unneeded_field_pattern)]
                 fn fmt(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
-                    #builder_tokens
+                    #fmt_tokens
                 }
             }
         };
@@ -648,3 +861,29 @@ fn combine_names(name: &str, variant_name: String) -> String {
         name
     }
 }
+
+fn find_repr_type(ast: &DeriveInput) -> String {
+    for attr in ast.attrs.iter() {
+        if let Ok(Meta::List(list)) = attr.parse_meta() {
+            if !list.path.is_ident("repr") {
+                continue;
+            }
+
+            for p in list.nested.iter() {
+                if let NestedMeta::Meta(Meta::Path(path)) = p {
+                    if let Some(ident) = path.get_ident() {
+                        let ty = ident.to_string();
+
+                        match ty.as_str() {
+                            "u8" | "u16" | "u32" | "u64" | "u128" | "usize" | "i8" | "i16"
+                            | "i32" | "i64" | "i128" | "isize" => return ty,
+                            _ => (),
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    String::from("isize")
+}
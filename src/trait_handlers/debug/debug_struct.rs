@@ -1,8 +1,10 @@
 use std::str::FromStr;
 
 use super::super::TraitHandler;
+use super::{create_generics_name_prefix, create_limited_format_arg, create_max_depth_body};
 use super::models::{
     FieldAttributeBuilder, FieldAttributeName, TypeAttributeBuilder, TypeAttributeName,
+    TypeAttributeWithGenerics,
 };
 
 use crate::panic;
@@ -39,19 +41,46 @@ impl TraitHandler for DebugStructHandler {
             named_field: !is_tuple,
             enable_named_field: true,
             enable_bound: true,
+            non_exhaustive: false,
+            enable_non_exhaustive: true,
+            enable_rename_all: true,
+            enable_style: false,
+            enable_with_generics: true,
+            enable_max_depth: true,
+            enable_union_format: false,
         }
         .from_debug_meta(meta);
 
-        let name = type_attribute.name.into_string_by_ident(&ast.ident);
+        let mut name = type_attribute.name.into_string_by_ident(&ast.ident);
 
         let named_field = type_attribute.named_field;
 
+        let non_exhaustive = type_attribute.non_exhaustive;
+
+        let rename_all = type_attribute.rename_all;
+
         let bound = type_attribute
             .bound
             .into_punctuated_where_predicates_by_generic_parameters(&ast.generics.params);
 
         let mut builder_tokens = TokenStream::new();
+
+        if type_attribute.with_generics != TypeAttributeWithGenerics::None && name.is_empty() {
+            panic::with_generics_without_name();
+        }
+
+        if let Some(prefix) =
+            create_generics_name_prefix(&name, &ast.generics, type_attribute.with_generics)
+        {
+            builder_tokens.extend(TokenStream::from_str(&prefix).unwrap());
+
+            name = String::from(">");
+        }
+
         let mut has_fields = false;
+        let mut has_ignored_fields = false;
+
+        let mut fields_tokens = TokenStream::new();
 
         if named_field {
             if name.is_empty() {
@@ -64,9 +93,21 @@ impl TraitHandler for DebugStructHandler {
                         }
                     }
                 ));
-                builder_tokens.extend(quote!(let mut builder = formatter.debug_map();));
+                builder_tokens.extend(quote!(
+                    let mut builder = formatter.debug_map();
+
+                    self.__educe_debug_fields(&mut |key, value| {
+                        builder.entry(&RawString(key), value);
+                    });
+                ));
             } else {
-                builder_tokens.extend(quote!(let mut builder = formatter.debug_struct(#name);));
+                builder_tokens.extend(quote!(
+                    let mut builder = formatter.debug_struct(#name);
+
+                    self.__educe_debug_fields(&mut |key, value| {
+                        builder.field(key, value);
+                    });
+                ));
             }
 
             if let Data::Struct(data) = &ast.data {
@@ -76,10 +117,13 @@ impl TraitHandler for DebugStructHandler {
                         enable_name: true,
                         enable_ignore: true,
                         enable_impl: true,
+                        enable_limit: true,
+                        enable_flatten: true,
                     }
                     .from_attributes(&field.attrs, traits);
 
                     if field_attribute.ignore {
+                        has_ignored_fields = true;
                         continue;
                     }
 
@@ -87,6 +131,9 @@ impl TraitHandler for DebugStructHandler {
 
                     let format_trait = field_attribute.format_trait;
                     let format_method = field_attribute.format_method;
+                    let max_items = field_attribute.max_items;
+                    let max_len = field_attribute.max_len;
+                    let sorted = field_attribute.sorted;
 
                     let (key, field_name) = match rename {
                         Some(rename) => {
@@ -98,18 +145,31 @@ impl TraitHandler for DebugStructHandler {
                         }
                         None => {
                             if let Some(ident) = field.ident.as_ref() {
-                                (ident.to_string(), ident.to_string())
+                                (rename_all.rename(&ident.to_string()), ident.to_string())
                             } else {
                                 (format!("_{}", index), format!("{}", index))
                             }
                         }
                     };
 
+                    if field_attribute.flatten {
+                        let statement = format!(
+                            "self.{field_name}.__educe_debug_fields(f);",
+                            field_name = field_name
+                        );
+
+                        fields_tokens.extend(TokenStream::from_str(&statement).unwrap());
+
+                        has_fields = true;
+
+                        continue;
+                    }
+
                     match format_trait {
                         Some(format_trait) => {
                             let format_method = format_method.unwrap();
 
-                            builder_tokens.extend(TokenStream::from_str(&format!("
+                            fields_tokens.extend(TokenStream::from_str(&format!("
                                 let arg = {{
                                     struct MyDebug<'a, T: {format_trait}>(&'a T);
 
@@ -123,20 +183,16 @@ impl TraitHandler for DebugStructHandler {
                                 }};
                             ", format_trait = format_trait, format_method = format_method, field_name = field_name)).unwrap());
 
-                            let statement = if name.is_empty() {
-                                format!("builder.entry(&RawString({key:?}), &arg);", key = key)
-                            } else {
-                                format!("builder.field({key:?}, &arg);", key = key)
-                            };
+                            let statement = format!("f({key:?}, &arg);", key = key);
 
-                            builder_tokens.extend(TokenStream::from_str(&statement).unwrap());
+                            fields_tokens.extend(TokenStream::from_str(&statement).unwrap());
                         }
                         None => {
                             match format_method {
                                 Some(format_method) => {
                                     let ty = field.ty.clone().into_token_stream().to_string();
 
-                                    builder_tokens.extend(TokenStream::from_str(&format!("
+                                    fields_tokens.extend(TokenStream::from_str(&format!("
                                         let arg = {{
                                             struct MyDebug<'a>(&'a {ty});
 
@@ -150,34 +206,36 @@ impl TraitHandler for DebugStructHandler {
                                         }};
                                     ", ty = ty, format_method = format_method, field_name = field_name)).unwrap());
 
-                                    let statement = if name.is_empty() {
-                                        format!(
-                                            "builder.entry(&RawString({key:?}), &arg);",
-                                            key = key
-                                        )
-                                    } else {
-                                        format!("builder.field({key:?}, &arg);", key = key)
-                                    };
+                                    let statement = format!("f({key:?}, &arg);", key = key);
 
-                                    builder_tokens
+                                    fields_tokens
                                         .extend(TokenStream::from_str(&statement).unwrap());
                                 }
                                 None => {
-                                    let statement = if name.is_empty() {
-                                        format!(
-                                        "builder.entry(&RawString({key:?}), &self.{field_name});",
-                                        key = key,
-                                        field_name = field_name
-                                    )
-                                    } else {
-                                        format!(
-                                            "builder.field({key:?}, &self.{field_name});",
-                                            key = key,
-                                            field_name = field_name
-                                        )
+                                    let limited_arg = create_limited_format_arg(
+                                        max_items,
+                                        max_len,
+                                        sorted,
+                                        &format!("&self.{field_name}", field_name = field_name),
+                                    );
+
+                                    let statement = match limited_arg {
+                                        Some(arg) => {
+                                            fields_tokens
+                                                .extend(TokenStream::from_str(&arg).unwrap());
+
+                                            format!("f({key:?}, &arg);", key = key)
+                                        }
+                                        None => {
+                                            format!(
+                                                "f({key:?}, &self.{field_name});",
+                                                key = key,
+                                                field_name = field_name
+                                            )
+                                        }
                                     };
 
-                                    builder_tokens
+                                    fields_tokens
                                         .extend(TokenStream::from_str(&statement).unwrap());
                                 }
                             }
@@ -197,15 +255,21 @@ impl TraitHandler for DebugStructHandler {
                         enable_name: false,
                         enable_ignore: true,
                         enable_impl: true,
+                        enable_limit: true,
+                        enable_flatten: false,
                     }
                     .from_attributes(&field.attrs, traits);
 
                     if field_attribute.ignore {
+                        has_ignored_fields = true;
                         continue;
                     }
 
                     let format_trait = field_attribute.format_trait;
                     let format_method = field_attribute.format_method;
+                    let max_items = field_attribute.max_items;
+                    let max_len = field_attribute.max_len;
+                    let sorted = field_attribute.sorted;
 
                     let field_name = if let Some(ident) = field.ident.as_ref() {
                         ident.to_string()
@@ -258,11 +322,28 @@ impl TraitHandler for DebugStructHandler {
                                     );
                                 }
                                 None => {
-                                    let statement = format!(
-                                        "builder.field(&self.{field_name});",
-                                        field_name = field_name
+                                    let limited_arg = create_limited_format_arg(
+                                        max_items,
+                                        max_len,
+                                        sorted,
+                                        &format!("&self.{field_name}", field_name = field_name),
                                     );
 
+                                    let statement = match limited_arg {
+                                        Some(arg) => {
+                                            builder_tokens
+                                                .extend(TokenStream::from_str(&arg).unwrap());
+
+                                            String::from("builder.field(&arg);")
+                                        }
+                                        None => {
+                                            format!(
+                                                "builder.field(&self.{field_name});",
+                                                field_name = field_name
+                                            )
+                                        }
+                                    };
+
                                     builder_tokens
                                         .extend(TokenStream::from_str(&statement).unwrap());
                                 }
@@ -279,6 +360,12 @@ impl TraitHandler for DebugStructHandler {
             panic::unit_struct_need_name();
         }
 
+        let finish_tokens = if non_exhaustive && has_ignored_fields {
+            quote!(builder.finish_non_exhaustive())
+        } else {
+            quote!(builder.finish())
+        };
+
         let ident = &ast.ident;
 
         let mut generics_cloned: Generics = ast.generics.clone();
@@ -291,16 +378,38 @@ impl TraitHandler for DebugStructHandler {
 
         let (impl_generics, ty_generics, where_clause) = generics_cloned.split_for_impl();
 
+        let fmt_tokens = create_max_depth_body(
+            type_attribute.max_depth,
+            quote!(
+                #builder_tokens
+                #finish_tokens
+            ),
+        );
+
         let debug_impl = quote! {
             impl #impl_generics core::fmt::Debug for #ident #ty_generics #where_clause {
                 #[inline]
                 fn fmt(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
-                    #builder_tokens
-                    builder.finish()
+                    #fmt_tokens
                 }
             }
         };
 
         tokens.extend(debug_impl);
+
+        if named_field {
+            let fields_impl = quote! {
+                impl #impl_generics #ident #ty_generics #where_clause {
+                    #[doc(hidden)]
+                    #[inline]
+                    #[allow(unused_variables)]
+                    pub fn __educe_debug_fields(&self, f: &mut dyn core::ops::FnMut(&'static str, &dyn core::fmt::Debug)) {
+                        #fields_tokens
+                    }
+                }
+            };
+
+            tokens.extend(fields_impl);
+        }
     }
 }
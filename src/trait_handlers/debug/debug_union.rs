@@ -1,8 +1,12 @@
-use super::super::TraitHandler;
+use std::str::FromStr;
+
+use super::super::{create_union_bytes_check, find_union_tag, TraitHandler};
 use super::models::{
     FieldAttributeBuilder, FieldAttributeName, TypeAttributeBuilder, TypeAttributeName,
+    TypeAttributeUnionFormat,
 };
 
+use crate::panic;
 use crate::proc_macro2::TokenStream;
 use crate::syn::{Data, DeriveInput, Generics, Meta};
 use crate::Trait;
@@ -23,6 +27,13 @@ impl TraitHandler for DebugUnionHandler {
             named_field: false,
             enable_named_field: false,
             enable_bound: true,
+            non_exhaustive: false,
+            enable_non_exhaustive: false,
+            enable_rename_all: false,
+            enable_style: false,
+            enable_with_generics: false,
+            enable_max_depth: false,
+            enable_union_format: true,
         }
         .from_debug_meta(meta);
 
@@ -41,29 +52,79 @@ impl TraitHandler for DebugUnionHandler {
                     enable_name: false,
                     enable_ignore: false,
                     enable_impl: false,
+                    enable_limit: false,
+                    enable_flatten: false,
                 }
                 .from_attributes(&field.attrs, traits);
             }
 
-            if name.is_empty() {
-                builder_tokens.extend(quote!(
-                    let size = core::mem::size_of::<Self>();
-                    let data = unsafe {{ core::slice::from_raw_parts(self as *const Self as *const u8, size) }};
-
-                    core::fmt::Debug::fmt(data, formatter)
-                ));
-            } else {
-                builder_tokens.extend(quote!(
-                    let mut builder = formatter.debug_tuple(#name);
-
-                    let size = core::mem::size_of::<Self>();
+            if let Some(union_tag) = find_union_tag(ast) {
+                if type_attribute.union_format != TypeAttributeUnionFormat::Checked {
+                    panic::set_union_tag_union_format();
+                }
 
-                    let data = unsafe {{ core::slice::from_raw_parts(self as *const Self as *const u8, size) }};
+                let mut match_tokens = format!("match {union_tag}(self) {{", union_tag = union_tag);
+
+                for field in data.fields.named.iter() {
+                    let field_name = field.ident.as_ref().unwrap().to_string();
+
+                    if name.is_empty() {
+                        match_tokens.push_str(&format!(
+                            "{field_name:?} => core::fmt::Debug::fmt(unsafe {{ &self.{field_name} }}, formatter),",
+                            field_name = field_name
+                        ));
+                    } else {
+                        match_tokens.push_str(&format!(
+                            "{field_name:?} => {{ let mut builder = formatter.debug_struct({name:?}); builder.field({field_name:?}, unsafe {{ &self.{field_name} }}); builder.finish() }}",
+                            name = name,
+                            field_name = field_name
+                        ));
+                    }
+                }
 
-                    builder.field(&data);
+                match_tokens.push_str(
+                    "_ => core::panic!(\"The union tag returned an unknown field name.\"), }",
+                );
 
-                    builder.finish()
-                ));
+                builder_tokens.extend(TokenStream::from_str(&match_tokens).unwrap());
+            } else {
+                match type_attribute.union_format {
+                    TypeAttributeUnionFormat::Opaque => {
+                        if name.is_empty() {
+                            builder_tokens.extend(quote!(formatter.write_str("..")));
+                        } else {
+                            builder_tokens.extend(quote!(
+                                formatter.debug_struct(#name).finish_non_exhaustive()
+                            ));
+                        }
+                    }
+                    _ => {
+                        if type_attribute.union_format == TypeAttributeUnionFormat::Checked {
+                            tokens.extend(create_union_bytes_check(ast));
+                        }
+
+                        if name.is_empty() {
+                            builder_tokens.extend(quote!(
+                                let size = core::mem::size_of::<Self>();
+                                let data = unsafe {{ core::slice::from_raw_parts(self as *const Self as *const u8, size) }};
+
+                                core::fmt::Debug::fmt(data, formatter)
+                            ));
+                        } else {
+                            builder_tokens.extend(quote!(
+                                let mut builder = formatter.debug_tuple(#name);
+
+                                let size = core::mem::size_of::<Self>();
+
+                                let data = unsafe {{ core::slice::from_raw_parts(self as *const Self as *const u8, size) }};
+
+                                builder.field(&data);
+
+                                builder.finish()
+                            ));
+                        }
+                    }
+                }
             }
         }
 
@@ -4,15 +4,21 @@ mod debug_enum;
 mod debug_struct;
 mod debug_union;
 
+use std::fmt::Write;
+use std::str::FromStr;
+
 use super::TraitHandler;
 
 use crate::proc_macro2::TokenStream;
-use crate::syn::{Data, DeriveInput, Meta};
+use crate::syn::{Data, DeriveInput, GenericParam, Generics, Meta};
 use crate::Trait;
 
 use debug_enum::DebugEnumHandler;
 use debug_struct::DebugStructHandler;
 use debug_union::DebugUnionHandler;
+use models::TypeAttributeWithGenerics;
+
+const MAX_DEPTH_FILL_BASE: u32 = 0xF0000;
 
 pub struct DebugHandler;
 
@@ -36,3 +42,339 @@ impl TraitHandler for DebugHandler {
         }
     }
 }
+
+// The depth is carried to the fields by the fill character of the formatter, which has no effect without a width.
+fn create_max_depth_body(max_depth: Option<usize>, body: TokenStream) -> TokenStream {
+    let max_depth = match max_depth {
+        Some(max_depth) => max_depth,
+        None => return body,
+    };
+
+    let mut arms = String::new();
+
+    for depth in 1..=max_depth {
+        let pattern = if depth < max_depth {
+            depth.to_string()
+        } else {
+            String::from("_")
+        };
+
+        arms.write_fmt(format_args!(
+            "{pattern} => write_body!(\"\\u{{{fill:x}}}\"),",
+            pattern = pattern,
+            fill = MAX_DEPTH_FILL_BASE + depth as u32
+        ))
+        .unwrap();
+    }
+
+    let arms = TokenStream::from_str(&arms).unwrap();
+
+    quote! {
+        let depth = match formatter.fill() as u32 {
+            c if c > #MAX_DEPTH_FILL_BASE && c <= #MAX_DEPTH_FILL_BASE + 64 => (c - #MAX_DEPTH_FILL_BASE) as usize,
+            _ => 0,
+        };
+
+        if depth >= #max_depth {
+            return formatter.write_str("..");
+        }
+
+        struct Body<F: Fn(&mut core::fmt::Formatter) -> core::fmt::Result>(F);
+
+        impl<F: Fn(&mut core::fmt::Formatter) -> core::fmt::Result> core::fmt::Debug for Body<F> {
+            fn fmt(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+                (self.0)(formatter)
+            }
+        }
+
+        let body = Body(|formatter: &mut core::fmt::Formatter| -> core::fmt::Result {
+            #body
+        });
+
+        if formatter.width().is_some() || formatter.sign_plus() || formatter.sign_aware_zero_pad() {
+            return core::fmt::Debug::fmt(&body, formatter);
+        }
+
+        macro_rules! write_body {
+            ($fill:literal) => {
+                match (formatter.alternate(), formatter.precision()) {
+                    (false, None) => formatter.write_fmt(format_args!(concat!("{:", $fill, "<?}"), body)),
+                    (true, None) => formatter.write_fmt(format_args!(concat!("{:", $fill, "<#?}"), body)),
+                    (false, Some(precision)) => formatter.write_fmt(format_args!(concat!("{:", $fill, "<.*?}"), precision, body)),
+                    (true, Some(precision)) => formatter.write_fmt(format_args!(concat!("{:", $fill, "<#.*?}"), precision, body)),
+                }
+            };
+        }
+
+        match depth + 1 {
+            #arms
+        }
+    }
+}
+
+fn create_flatten_statement(value: &str, callback: &str) -> String {
+    format!("{value}.__educe_debug_fields(&mut |key, value| {{ {callback} }});", value = value, callback = callback)
+}
+
+fn create_generics_name_prefix(
+    name: &str,
+    generics: &Generics,
+    with_generics: TypeAttributeWithGenerics,
+) -> Option<String> {
+    if with_generics == TypeAttributeWithGenerics::None {
+        return None;
+    }
+
+    let mut arguments = Vec::new();
+
+    for param in generics.params.iter() {
+        match param {
+            GenericParam::Type(ty) => {
+                let type_name = format!("core::any::type_name::<{}>()", ty.ident);
+
+                if with_generics == TypeAttributeWithGenerics::Short {
+                    arguments.push(format!("write_short_type_name(formatter, {})?;", type_name));
+                } else {
+                    arguments.push(format!("formatter.write_str({})?;", type_name));
+                }
+            }
+            GenericParam::Const(c) => {
+                arguments.push(format!("core::fmt::Display::fmt(&{}, formatter)?;", c.ident));
+            }
+            GenericParam::Lifetime(_) => (),
+        }
+    }
+
+    if arguments.is_empty() {
+        return None;
+    }
+
+    let mut statements = String::new();
+
+    if with_generics == TypeAttributeWithGenerics::Short {
+        statements.push_str("
+            fn write_short_type_name(formatter: &mut core::fmt::Formatter, s: &str) -> core::fmt::Result {
+                let bytes = s.as_bytes();
+
+                let mut start = 0;
+                let mut i = 0;
+
+                while i < bytes.len() {
+                    let c = bytes[i];
+
+                    if c == b':' && i + 1 < bytes.len() && bytes[i + 1] == b':' {
+                        i += 2;
+                        start = i;
+                    } else if c == b'_' || c >= 0x80 || c.is_ascii_alphanumeric() {
+                        i += 1;
+                    } else {
+                        formatter.write_str(&s[start..=i])?;
+                        i += 1;
+                        start = i;
+                    }
+                }
+
+                formatter.write_str(&s[start..])
+            }
+        ");
+    }
+
+    statements.push_str(&format!("formatter.write_str({:?})?;", format!("{}<", name)));
+
+    for (i, argument) in arguments.iter().enumerate() {
+        if i > 0 {
+            statements.push_str("formatter.write_str(\", \")?;");
+        }
+
+        statements.push_str(argument);
+    }
+
+    Some(statements)
+}
+
+fn create_limited_format_arg(
+    max_items: Option<usize>,
+    max_len: Option<usize>,
+    sorted: bool,
+    field: &str,
+) -> Option<String> {
+    if sorted {
+        // The entries are printed key by key without allocating.
+        return Some(format!("
+            let arg = {{
+                trait SortedEntry {{
+                    type Key: ?Sized + core::cmp::Ord;
+
+                    const IS_MAP: bool;
+
+                    fn sort_key(&self) -> &Self::Key;
+
+                    fn key(&self) -> &dyn core::fmt::Debug;
+
+                    fn value(&self) -> &dyn core::fmt::Debug;
+                }}
+
+                impl<'a, K: core::cmp::Ord + core::fmt::Debug, V: core::fmt::Debug> SortedEntry for (&'a K, &'a V) {{
+                    type Key = K;
+
+                    const IS_MAP: bool = true;
+
+                    fn sort_key(&self) -> &K {{
+                        self.0
+                    }}
+
+                    fn key(&self) -> &dyn core::fmt::Debug {{
+                        self.0
+                    }}
+
+                    fn value(&self) -> &dyn core::fmt::Debug {{
+                        self.1
+                    }}
+                }}
+
+                impl<'a, T: core::cmp::Ord + core::fmt::Debug> SortedEntry for &'a T {{
+                    type Key = T;
+
+                    const IS_MAP: bool = false;
+
+                    fn sort_key(&self) -> &T {{
+                        self
+                    }}
+
+                    fn key(&self) -> &dyn core::fmt::Debug {{
+                        *self
+                    }}
+
+                    fn value(&self) -> &dyn core::fmt::Debug {{
+                        *self
+                    }}
+                }}
+
+                fn for_each_sorted<I: core::iter::IntoIterator + Copy>(iter: I, mut f: impl FnMut(&I::Item)) where I::Item: SortedEntry {{
+                    let mut last: Option<I::Item> = None;
+
+                    loop {{
+                        let mut next: Option<I::Item> = None;
+
+                        for item in iter {{
+                            if let Some(last) = &last {{
+                                if item.sort_key() <= last.sort_key() {{
+                                    continue;
+                                }}
+                            }}
+
+                            let is_smaller = match &next {{
+                                Some(next) => item.sort_key() < next.sort_key(),
+                                None => true,
+                            }};
+
+                            if is_smaller {{
+                                next = Some(item);
+                            }}
+                        }}
+
+                        match next {{
+                            Some(next) => {{
+                                for item in iter {{
+                                    if item.sort_key() == next.sort_key() {{
+                                        f(&item);
+                                    }}
+                                }}
+
+                                last = Some(next);
+                            }}
+                            None => break,
+                        }}
+                    }}
+                }}
+
+                struct MyDebug<'a, T: ?Sized>(&'a T);
+
+                impl<'a, T: ?Sized> core::fmt::Debug for MyDebug<'a, T> where &'a T: core::iter::IntoIterator, <&'a T as core::iter::IntoIterator>::Item: SortedEntry {{
+                    fn fmt(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {{
+                        if <<&'a T as core::iter::IntoIterator>::Item as SortedEntry>::IS_MAP {{
+                            let mut builder = formatter.debug_map();
+
+                            for_each_sorted(self.0, |item| {{
+                                builder.entry(item.key(), item.value());
+                            }});
+
+                            builder.finish()
+                        }} else {{
+                            let mut builder = formatter.debug_set();
+
+                            for_each_sorted(self.0, |item| {{
+                                builder.entry(item.key());
+                            }});
+
+                            builder.finish()
+                        }}
+                    }}
+                }}
+
+                MyDebug({field})
+            }};
+        ", field = field));
+    }
+
+    match (max_items, max_len) {
+        (Some(max_items), _) => Some(format!("
+            let arg = {{
+                struct MyDebug<'a, T: ?Sized>(&'a T);
+
+                impl<'a, T: ?Sized> core::fmt::Debug for MyDebug<'a, T> where &'a T: core::iter::IntoIterator, <&'a T as core::iter::IntoIterator>::Item: core::fmt::Debug {{
+                    fn fmt(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {{
+                        struct More(usize);
+
+                        impl core::fmt::Debug for More {{
+                            fn fmt(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {{
+                                formatter.write_fmt(format_args!(\"... ({{}} more)\", self.0))
+                            }}
+                        }}
+
+                        let mut iter = core::iter::IntoIterator::into_iter(self.0);
+
+                        let mut builder = formatter.debug_list();
+
+                        for item in (&mut iter).take({max_items}) {{
+                            builder.entry(&item);
+                        }}
+
+                        let more = iter.count();
+
+                        if more > 0 {{
+                            builder.entry(&More(more));
+                        }}
+
+                        builder.finish()
+                    }}
+                }}
+
+                MyDebug({field})
+            }};
+        ", max_items = max_items, field = field)),
+        (None, Some(max_len)) => Some(format!("
+            let arg = {{
+                struct MyDebug<'a, T: ?Sized>(&'a T);
+
+                impl<'a, T: ?Sized + core::convert::AsRef<str>> core::fmt::Debug for MyDebug<'a, T> {{
+                    fn fmt(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {{
+                        let s = core::convert::AsRef::<str>::as_ref(self.0);
+
+                        match s.char_indices().nth({max_len}) {{
+                            Some((index, _)) => {{
+                                core::fmt::Debug::fmt(&s[..index], formatter)?;
+
+                                formatter.write_fmt(format_args!(\"... ({{}} more)\", s[index..].chars().count()))
+                            }}
+                            None => core::fmt::Debug::fmt(s, formatter),
+                        }}
+                    }}
+                }}
+
+                MyDebug({field})
+            }};
+        ", max_len = max_len, field = field)),
+        (None, None) => None,
+    }
+}
@@ -26,6 +26,10 @@ pub struct FieldAttribute {
     pub ignore: bool,
     pub format_method: Option<String>,
     pub format_trait: Option<String>,
+    pub max_items: Option<usize>,
+    pub max_len: Option<usize>,
+    pub flatten: bool,
+    pub sorted: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -34,6 +38,8 @@ pub struct FieldAttributeBuilder {
     pub enable_name: bool,
     pub enable_ignore: bool,
     pub enable_impl: bool,
+    pub enable_limit: bool,
+    pub enable_flatten: bool,
 }
 
 impl FieldAttributeBuilder {
@@ -46,6 +52,12 @@ impl FieldAttributeBuilder {
         let mut format_method = None;
         let mut format_trait = None;
 
+        let mut max_items = None;
+        let mut max_len = None;
+
+        let mut flatten = false;
+        let mut sorted = false;
+
         let correct_usage_for_debug_attribute = {
             let mut usage = vec![];
 
@@ -90,10 +102,42 @@ impl FieldAttributeBuilder {
             usage
         };
 
+        let correct_usage_for_flatten = {
+            let usage = vec![stringify!(#[educe(Debug(flatten))])];
+
+            usage
+        };
+
+        let correct_usage_for_sorted = {
+            let usage = vec![stringify!(#[educe(Debug(sorted))])];
+
+            usage
+        };
+
+        let correct_usage_for_max_items = {
+            let usage = vec![
+                stringify!(#[educe(Debug(max_items = number))]),
+                stringify!(#[educe(Debug(max_items(number)))]),
+            ];
+
+            usage
+        };
+
+        let correct_usage_for_max_len = {
+            let usage = vec![
+                stringify!(#[educe(Debug(max_len = number))]),
+                stringify!(#[educe(Debug(max_len(number)))]),
+            ];
+
+            usage
+        };
+
         match meta {
             Meta::List(list) => {
                 let mut name_is_set = false;
                 let mut ignore_is_set = false;
+                let mut flatten_is_set = false;
+                let mut sorted_is_set = false;
 
                 for p in list.nested.iter() {
                     match p {
@@ -240,6 +284,52 @@ impl FieldAttributeBuilder {
                                         }
                                     }
                                 }
+                                "flatten" => {
+                                    if !self.enable_flatten {
+                                        panic::unknown_parameter("Debug", meta_name.as_str());
+                                    }
+
+                                    match meta {
+                                        Meta::Path(_) => {
+                                            if flatten_is_set {
+                                                panic::reset_parameter(meta_name.as_str());
+                                            }
+
+                                            flatten_is_set = true;
+
+                                            flatten = true;
+                                        }
+                                        _ => {
+                                            panic::parameter_incorrect_format(
+                                                meta_name.as_str(),
+                                                &correct_usage_for_flatten,
+                                            )
+                                        }
+                                    }
+                                }
+                                "sorted" => {
+                                    if !self.enable_limit {
+                                        panic::unknown_parameter("Debug", meta_name.as_str());
+                                    }
+
+                                    match meta {
+                                        Meta::Path(_) => {
+                                            if sorted_is_set {
+                                                panic::reset_parameter(meta_name.as_str());
+                                            }
+
+                                            sorted_is_set = true;
+
+                                            sorted = true;
+                                        }
+                                        _ => {
+                                            panic::parameter_incorrect_format(
+                                                meta_name.as_str(),
+                                                &correct_usage_for_sorted,
+                                            )
+                                        }
+                                    }
+                                }
                                 "method" => {
                                     if !self.enable_impl {
                                         panic::unknown_parameter("Debug", meta_name.as_str());
@@ -402,6 +492,134 @@ impl FieldAttributeBuilder {
                                         }
                                     }
                                 }
+                                "max_items" => {
+                                    if !self.enable_limit {
+                                        panic::unknown_parameter("Debug", meta_name.as_str());
+                                    }
+
+                                    match meta {
+                                        Meta::List(list) => {
+                                            for p in list.nested.iter() {
+                                                match p {
+                                                    NestedMeta::Lit(lit) => {
+                                                        match lit {
+                                                            Lit::Int(i) => {
+                                                                if max_items.is_some() {
+                                                                    panic::reset_parameter(
+                                                                        meta_name.as_str(),
+                                                                    );
+                                                                }
+
+                                                                max_items = Some(i.base10_parse().unwrap());
+                                                            }
+                                                            _ => {
+                                                                panic::parameter_incorrect_format(
+                                                                    meta_name.as_str(),
+                                                                    &correct_usage_for_max_items,
+                                                                )
+                                                            }
+                                                        }
+                                                    }
+                                                    _ => {
+                                                        panic::parameter_incorrect_format(
+                                                            meta_name.as_str(),
+                                                            &correct_usage_for_max_items,
+                                                        )
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        Meta::NameValue(named_value) => {
+                                            let lit = &named_value.lit;
+
+                                            match lit {
+                                                Lit::Int(i) => {
+                                                    if max_items.is_some() {
+                                                        panic::reset_parameter(meta_name.as_str());
+                                                    }
+
+                                                    max_items = Some(i.base10_parse().unwrap());
+                                                }
+                                                _ => {
+                                                    panic::parameter_incorrect_format(
+                                                        meta_name.as_str(),
+                                                        &correct_usage_for_max_items,
+                                                    )
+                                                }
+                                            }
+                                        }
+                                        _ => {
+                                            panic::parameter_incorrect_format(
+                                                meta_name.as_str(),
+                                                &correct_usage_for_max_items,
+                                            )
+                                        }
+                                    }
+                                }
+                                "max_len" => {
+                                    if !self.enable_limit {
+                                        panic::unknown_parameter("Debug", meta_name.as_str());
+                                    }
+
+                                    match meta {
+                                        Meta::List(list) => {
+                                            for p in list.nested.iter() {
+                                                match p {
+                                                    NestedMeta::Lit(lit) => {
+                                                        match lit {
+                                                            Lit::Int(i) => {
+                                                                if max_len.is_some() {
+                                                                    panic::reset_parameter(
+                                                                        meta_name.as_str(),
+                                                                    );
+                                                                }
+
+                                                                max_len = Some(i.base10_parse().unwrap());
+                                                            }
+                                                            _ => {
+                                                                panic::parameter_incorrect_format(
+                                                                    meta_name.as_str(),
+                                                                    &correct_usage_for_max_len,
+                                                                )
+                                                            }
+                                                        }
+                                                    }
+                                                    _ => {
+                                                        panic::parameter_incorrect_format(
+                                                            meta_name.as_str(),
+                                                            &correct_usage_for_max_len,
+                                                        )
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        Meta::NameValue(named_value) => {
+                                            let lit = &named_value.lit;
+
+                                            match lit {
+                                                Lit::Int(i) => {
+                                                    if max_len.is_some() {
+                                                        panic::reset_parameter(meta_name.as_str());
+                                                    }
+
+                                                    max_len = Some(i.base10_parse().unwrap());
+                                                }
+                                                _ => {
+                                                    panic::parameter_incorrect_format(
+                                                        meta_name.as_str(),
+                                                        &correct_usage_for_max_len,
+                                                    )
+                                                }
+                                            }
+                                        }
+                                        _ => {
+                                            panic::parameter_incorrect_format(
+                                                meta_name.as_str(),
+                                                &correct_usage_for_max_len,
+                                            )
+                                        }
+                                    }
+                                }
                                 _ => panic::unknown_parameter("Debug", meta_name.as_str()),
                             }
                         }
@@ -499,11 +717,37 @@ impl FieldAttributeBuilder {
             format_method = Some("fmt".to_string());
         }
 
+        if max_items.is_some() && max_len.is_some() {
+            panic::set_max_items_max_len();
+        }
+
+        if sorted && (max_items.is_some() || max_len.is_some()) {
+            panic::set_sorted_limit();
+        }
+
+        if (max_items.is_some() || max_len.is_some() || sorted) && format_method.is_some() {
+            panic::set_limit_impl();
+        }
+
+        if flatten
+            && (matches!(name, FieldAttributeName::Custom(_))
+                || format_method.is_some()
+                || max_items.is_some()
+                || max_len.is_some()
+                || sorted)
+        {
+            panic::flatten_field_with_format();
+        }
+
         FieldAttribute {
             name,
             ignore,
             format_method,
             format_trait,
+            max_items,
+            max_len,
+            flatten,
+            sorted,
         }
     }
 
@@ -552,6 +796,10 @@ impl FieldAttributeBuilder {
             ignore: false,
             format_method: None,
             format_trait: None,
+            max_items: None,
+            max_len: None,
+            flatten: false,
+            sorted: false,
         })
     }
 }
@@ -28,6 +28,140 @@ impl TypeAttributeName {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TypeAttributeUnionFormat {
+    Checked,
+    Opaque,
+    UnsafeBytes,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TypeAttributeWithGenerics {
+    None,
+    Full,
+    Short,
+}
+
+impl TypeAttributeWithGenerics {
+    pub fn from_str(s: &str) -> Option<TypeAttributeWithGenerics> {
+        match s {
+            "full" => Some(TypeAttributeWithGenerics::Full),
+            "short" => Some(TypeAttributeWithGenerics::Short),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TypeAttributeStyle {
+    None,
+    Qualified,
+    Variant,
+    Discriminant,
+}
+
+impl TypeAttributeStyle {
+    pub fn from_str(s: &str) -> Option<TypeAttributeStyle> {
+        match s {
+            "qualified" => Some(TypeAttributeStyle::Qualified),
+            "variant" => Some(TypeAttributeStyle::Variant),
+            "discriminant" => Some(TypeAttributeStyle::Discriminant),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum TypeAttributeRenameAll {
+    None,
+    CamelCase,
+    SnakeCase,
+    ScreamingSnakeCase,
+    KebabCase,
+}
+
+impl TypeAttributeRenameAll {
+    pub fn from_str(s: &str) -> Option<TypeAttributeRenameAll> {
+        match s {
+            "camelCase" => Some(TypeAttributeRenameAll::CamelCase),
+            "snake_case" => Some(TypeAttributeRenameAll::SnakeCase),
+            "SCREAMING_SNAKE_CASE" => Some(TypeAttributeRenameAll::ScreamingSnakeCase),
+            "kebab-case" => Some(TypeAttributeRenameAll::KebabCase),
+            _ => None,
+        }
+    }
+
+    pub fn rename(&self, s: &str) -> String {
+        if let TypeAttributeRenameAll::None = self {
+            return s.to_string();
+        }
+
+        let mut words: Vec<String> = Vec::new();
+
+        let chars: Vec<char> = s.chars().collect();
+
+        let mut word = String::new();
+
+        for (i, c) in chars.iter().copied().enumerate() {
+            if c == '_' || c == '-' {
+                if !word.is_empty() {
+                    words.push(word);
+                    word = String::new();
+                }
+
+                continue;
+            }
+
+            if c.is_uppercase() && !word.is_empty() {
+                let prev = chars[i - 1];
+                let next_is_lowercase = chars.get(i + 1).map(|c| c.is_lowercase()).unwrap_or(false);
+
+                if !prev.is_uppercase() || next_is_lowercase {
+                    words.push(word);
+                    word = String::new();
+                }
+            }
+
+            word.push(c);
+        }
+
+        if !word.is_empty() {
+            words.push(word);
+        }
+
+        match self {
+            TypeAttributeRenameAll::None => unreachable!(),
+            TypeAttributeRenameAll::CamelCase => {
+                let mut result = String::new();
+
+                for (i, word) in words.iter().enumerate() {
+                    if i == 0 {
+                        result.push_str(&word.to_lowercase());
+                    } else {
+                        let mut chars = word.chars();
+
+                        if let Some(c) = chars.next() {
+                            result.extend(c.to_uppercase());
+                            result.push_str(&chars.as_str().to_lowercase());
+                        }
+                    }
+                }
+
+                result
+            }
+            TypeAttributeRenameAll::SnakeCase => {
+                words.iter().map(|word| word.to_lowercase()).collect::<Vec<String>>().join("_")
+            }
+            TypeAttributeRenameAll::ScreamingSnakeCase => {
+                words.iter().map(|word| word.to_uppercase()).collect::<Vec<String>>().join("_")
+            }
+            TypeAttributeRenameAll::KebabCase => {
+                words.iter().map(|word| word.to_lowercase()).collect::<Vec<String>>().join("-")
+            }
+        }
+    }
+}
+
 #[derive(Clone)]
 pub enum TypeAttributeBound {
     None,
@@ -59,6 +193,12 @@ pub struct TypeAttribute {
     pub name: TypeAttributeName,
     pub named_field: bool,
     pub bound: TypeAttributeBound,
+    pub non_exhaustive: bool,
+    pub rename_all: TypeAttributeRenameAll,
+    pub style: TypeAttributeStyle,
+    pub with_generics: TypeAttributeWithGenerics,
+    pub max_depth: Option<usize>,
+    pub union_format: TypeAttributeUnionFormat,
 }
 
 #[derive(Debug, Clone)]
@@ -69,6 +209,13 @@ pub struct TypeAttributeBuilder {
     pub named_field: bool,
     pub enable_named_field: bool,
     pub enable_bound: bool,
+    pub non_exhaustive: bool,
+    pub enable_non_exhaustive: bool,
+    pub enable_rename_all: bool,
+    pub enable_style: bool,
+    pub enable_with_generics: bool,
+    pub enable_max_depth: bool,
+    pub enable_union_format: bool,
 }
 
 impl TypeAttributeBuilder {
@@ -78,6 +225,12 @@ impl TypeAttributeBuilder {
         let mut name = self.name.clone();
         let mut named_field = self.named_field;
         let mut bound = TypeAttributeBound::None;
+        let mut non_exhaustive = self.non_exhaustive;
+        let mut rename_all = TypeAttributeRenameAll::None;
+        let mut style = TypeAttributeStyle::None;
+        let mut with_generics = TypeAttributeWithGenerics::None;
+        let mut max_depth: Option<usize> = None;
+        let mut union_format = TypeAttributeUnionFormat::Checked;
 
         let correct_usage_for_debug_attribute = {
             let mut usage = vec![];
@@ -139,11 +292,81 @@ impl TypeAttributeBuilder {
             usage
         };
 
+        let correct_usage_for_non_exhaustive = {
+            let usage = vec![
+                stringify!(#[educe(Debug(non_exhaustive))]),
+                stringify!(#[educe(Debug(non_exhaustive = false))]),
+                stringify!(#[educe(Debug(non_exhaustive(false)))]),
+            ];
+
+            usage
+        };
+
+        let correct_usage_for_rename_all = {
+            let usage = vec![
+                stringify!(#[educe(Debug(rename_all = "camelCase"))]),
+                stringify!(#[educe(Debug(rename_all = "snake_case"))]),
+                stringify!(#[educe(Debug(rename_all = "SCREAMING_SNAKE_CASE"))]),
+                stringify!(#[educe(Debug(rename_all = "kebab-case"))]),
+                stringify!(#[educe(Debug(rename_all("camelCase")))]),
+            ];
+
+            usage
+        };
+
+        let correct_usage_for_style = {
+            let usage = vec![
+                stringify!(#[educe(Debug(style = "qualified"))]),
+                stringify!(#[educe(Debug(style = "variant"))]),
+                stringify!(#[educe(Debug(style = "discriminant"))]),
+                stringify!(#[educe(Debug(style("qualified")))]),
+            ];
+
+            usage
+        };
+
+        let correct_usage_for_with_generics = {
+            let usage = vec![
+                stringify!(#[educe(Debug(with_generics))]),
+                stringify!(#[educe(Debug(with_generics = "full"))]),
+                stringify!(#[educe(Debug(with_generics = "short"))]),
+                stringify!(#[educe(Debug(with_generics("short")))]),
+            ];
+
+            usage
+        };
+
+        let correct_usage_for_max_depth = {
+            let usage = vec![
+                stringify!(#[educe(Debug(max_depth = 8))]),
+                stringify!(#[educe(Debug(max_depth(8)))]),
+            ];
+
+            usage
+        };
+
+        let correct_usage_for_opaque = {
+            let usage = vec![stringify!(#[educe(Debug(opaque))])];
+
+            usage
+        };
+
+        let correct_usage_for_unsafe_bytes = {
+            let usage = vec![stringify!(#[educe(Debug(unsafe_bytes))])];
+
+            usage
+        };
+
         match meta {
             Meta::List(list) => {
                 let mut name_is_set = false;
                 let mut named_field_is_set = false;
                 let mut bound_is_set = false;
+                let mut non_exhaustive_is_set = false;
+                let mut rename_all_is_set = false;
+                let mut style_is_set = false;
+                let mut with_generics_is_set = false;
+                let mut union_format_is_set = false;
 
                 for p in list.nested.iter() {
                     match p {
@@ -432,6 +655,430 @@ impl TypeAttributeBuilder {
                                         }
                                     }
                                 }
+                                "non_exhaustive" => {
+                                    if !self.enable_non_exhaustive {
+                                        panic::unknown_parameter("Debug", meta_name.as_str());
+                                    }
+
+                                    match meta {
+                                        Meta::List(list) => {
+                                            for p in list.nested.iter() {
+                                                match p {
+                                                    NestedMeta::Lit(lit) => {
+                                                        match lit {
+                                                            Lit::Bool(s) => {
+                                                                if non_exhaustive_is_set {
+                                                                    panic::reset_parameter(
+                                                                        meta_name.as_str(),
+                                                                    );
+                                                                }
+
+                                                                non_exhaustive_is_set = true;
+
+                                                                non_exhaustive = s.value;
+                                                            }
+                                                            _ => {
+                                                                panic::parameter_incorrect_format(
+                                                                    meta_name.as_str(),
+                                                                    &correct_usage_for_non_exhaustive,
+                                                                )
+                                                            }
+                                                        }
+                                                    }
+                                                    _ => {
+                                                        panic::parameter_incorrect_format(
+                                                            meta_name.as_str(),
+                                                            &correct_usage_for_non_exhaustive,
+                                                        )
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        Meta::NameValue(named_value) => {
+                                            let lit = &named_value.lit;
+
+                                            match lit {
+                                                Lit::Bool(s) => {
+                                                    if non_exhaustive_is_set {
+                                                        panic::reset_parameter(meta_name.as_str());
+                                                    }
+
+                                                    non_exhaustive_is_set = true;
+
+                                                    non_exhaustive = s.value;
+                                                }
+                                                _ => {
+                                                    panic::parameter_incorrect_format(
+                                                        meta_name.as_str(),
+                                                        &correct_usage_for_non_exhaustive,
+                                                    )
+                                                }
+                                            }
+                                        }
+                                        Meta::Path(_) => {
+                                            if non_exhaustive_is_set {
+                                                panic::reset_parameter(meta_name.as_str());
+                                            }
+
+                                            non_exhaustive_is_set = true;
+
+                                            non_exhaustive = true;
+                                        }
+                                    }
+                                }
+                                "rename_all" => {
+                                    if !self.enable_rename_all {
+                                        panic::unknown_parameter("Debug", meta_name.as_str());
+                                    }
+
+                                    match meta {
+                                        Meta::List(list) => {
+                                            for p in list.nested.iter() {
+                                                match p {
+                                                    NestedMeta::Lit(lit) => {
+                                                        match lit {
+                                                            Lit::Str(s) => {
+                                                                if rename_all_is_set {
+                                                                    panic::reset_parameter(
+                                                                        meta_name.as_str(),
+                                                                    );
+                                                                }
+
+                                                                rename_all_is_set = true;
+
+                                                                rename_all = match TypeAttributeRenameAll::from_str(&s.value()) {
+                                                                    Some(rename_all) => rename_all,
+                                                                    None => {
+                                                                        panic::parameter_incorrect_format(
+                                                                            meta_name.as_str(),
+                                                                            &correct_usage_for_rename_all,
+                                                                        )
+                                                                    }
+                                                                };
+                                                            }
+                                                            _ => {
+                                                                panic::parameter_incorrect_format(
+                                                                    meta_name.as_str(),
+                                                                    &correct_usage_for_rename_all,
+                                                                )
+                                                            }
+                                                        }
+                                                    }
+                                                    _ => {
+                                                        panic::parameter_incorrect_format(
+                                                            meta_name.as_str(),
+                                                            &correct_usage_for_rename_all,
+                                                        )
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        Meta::NameValue(named_value) => {
+                                            let lit = &named_value.lit;
+
+                                            match lit {
+                                                Lit::Str(s) => {
+                                                    if rename_all_is_set {
+                                                        panic::reset_parameter(meta_name.as_str());
+                                                    }
+
+                                                    rename_all_is_set = true;
+
+                                                    rename_all = match TypeAttributeRenameAll::from_str(&s.value()) {
+                                                        Some(rename_all) => rename_all,
+                                                        None => {
+                                                            panic::parameter_incorrect_format(
+                                                                meta_name.as_str(),
+                                                                &correct_usage_for_rename_all,
+                                                            )
+                                                        }
+                                                    };
+                                                }
+                                                _ => {
+                                                    panic::parameter_incorrect_format(
+                                                        meta_name.as_str(),
+                                                        &correct_usage_for_rename_all,
+                                                    )
+                                                }
+                                            }
+                                        }
+                                        _ => {
+                                            panic::parameter_incorrect_format(
+                                                meta_name.as_str(),
+                                                &correct_usage_for_rename_all,
+                                            )
+                                        }
+                                    }
+                                }
+                                "style" => {
+                                    if !self.enable_style {
+                                        panic::unknown_parameter("Debug", meta_name.as_str());
+                                    }
+
+                                    match meta {
+                                        Meta::List(list) => {
+                                            for p in list.nested.iter() {
+                                                match p {
+                                                    NestedMeta::Lit(lit) => {
+                                                        match lit {
+                                                            Lit::Str(s) => {
+                                                                if style_is_set {
+                                                                    panic::reset_parameter(
+                                                                        meta_name.as_str(),
+                                                                    );
+                                                                }
+
+                                                                style_is_set = true;
+
+                                                                style = match TypeAttributeStyle::from_str(&s.value()) {
+                                                                    Some(style) => style,
+                                                                    None => {
+                                                                        panic::parameter_incorrect_format(
+                                                                            meta_name.as_str(),
+                                                                            &correct_usage_for_style,
+                                                                        )
+                                                                    }
+                                                                };
+                                                            }
+                                                            _ => {
+                                                                panic::parameter_incorrect_format(
+                                                                    meta_name.as_str(),
+                                                                    &correct_usage_for_style,
+                                                                )
+                                                            }
+                                                        }
+                                                    }
+                                                    _ => {
+                                                        panic::parameter_incorrect_format(
+                                                            meta_name.as_str(),
+                                                            &correct_usage_for_style,
+                                                        )
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        Meta::NameValue(named_value) => {
+                                            let lit = &named_value.lit;
+
+                                            match lit {
+                                                Lit::Str(s) => {
+                                                    if style_is_set {
+                                                        panic::reset_parameter(meta_name.as_str());
+                                                    }
+
+                                                    style_is_set = true;
+
+                                                    style = match TypeAttributeStyle::from_str(&s.value()) {
+                                                        Some(style) => style,
+                                                        None => {
+                                                            panic::parameter_incorrect_format(
+                                                                meta_name.as_str(),
+                                                                &correct_usage_for_style,
+                                                            )
+                                                        }
+                                                    };
+                                                }
+                                                _ => {
+                                                    panic::parameter_incorrect_format(
+                                                        meta_name.as_str(),
+                                                        &correct_usage_for_style,
+                                                    )
+                                                }
+                                            }
+                                        }
+                                        _ => {
+                                            panic::parameter_incorrect_format(
+                                                meta_name.as_str(),
+                                                &correct_usage_for_style,
+                                            )
+                                        }
+                                    }
+                                }
+                                "with_generics" => {
+                                    if !self.enable_with_generics {
+                                        panic::unknown_parameter("Debug", meta_name.as_str());
+                                    }
+
+                                    match meta {
+                                        Meta::Path(_) => {
+                                            if with_generics_is_set {
+                                                panic::reset_parameter(meta_name.as_str());
+                                            }
+
+                                            with_generics_is_set = true;
+
+                                            with_generics = TypeAttributeWithGenerics::Full;
+                                        }
+                                        Meta::List(list) => {
+                                            for p in list.nested.iter() {
+                                                match p {
+                                                    NestedMeta::Lit(lit) => {
+                                                        match lit {
+                                                            Lit::Str(s) => {
+                                                                if with_generics_is_set {
+                                                                    panic::reset_parameter(
+                                                                        meta_name.as_str(),
+                                                                    );
+                                                                }
+
+                                                                with_generics_is_set = true;
+
+                                                                with_generics = match TypeAttributeWithGenerics::from_str(&s.value()) {
+                                                                    Some(with_generics) => with_generics,
+                                                                    None => {
+                                                                        panic::parameter_incorrect_format(
+                                                                            meta_name.as_str(),
+                                                                            &correct_usage_for_with_generics,
+                                                                        )
+                                                                    }
+                                                                };
+                                                            }
+                                                            _ => {
+                                                                panic::parameter_incorrect_format(
+                                                                    meta_name.as_str(),
+                                                                    &correct_usage_for_with_generics,
+                                                                )
+                                                            }
+                                                        }
+                                                    }
+                                                    _ => {
+                                                        panic::parameter_incorrect_format(
+                                                            meta_name.as_str(),
+                                                            &correct_usage_for_with_generics,
+                                                        )
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        Meta::NameValue(named_value) => {
+                                            let lit = &named_value.lit;
+
+                                            match lit {
+                                                Lit::Str(s) => {
+                                                    if with_generics_is_set {
+                                                        panic::reset_parameter(meta_name.as_str());
+                                                    }
+
+                                                    with_generics_is_set = true;
+
+                                                    with_generics = match TypeAttributeWithGenerics::from_str(&s.value()) {
+                                                        Some(with_generics) => with_generics,
+                                                        None => {
+                                                            panic::parameter_incorrect_format(
+                                                                meta_name.as_str(),
+                                                                &correct_usage_for_with_generics,
+                                                            )
+                                                        }
+                                                    };
+                                                }
+                                                _ => {
+                                                    panic::parameter_incorrect_format(
+                                                        meta_name.as_str(),
+                                                        &correct_usage_for_with_generics,
+                                                    )
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                                "max_depth" => {
+                                    if !self.enable_max_depth {
+                                        panic::unknown_parameter("Debug", meta_name.as_str());
+                                    }
+
+                                    match meta {
+                                        Meta::List(list) => {
+                                            for p in list.nested.iter() {
+                                                match p {
+                                                    NestedMeta::Lit(lit) => {
+                                                        match lit {
+                                                            Lit::Int(i) => {
+                                                                if max_depth.is_some() {
+                                                                    panic::reset_parameter(
+                                                                        meta_name.as_str(),
+                                                                    );
+                                                                }
+
+                                                                max_depth = Some(i.base10_parse().unwrap());
+                                                            }
+                                                            _ => {
+                                                                panic::parameter_incorrect_format(
+                                                                    meta_name.as_str(),
+                                                                    &correct_usage_for_max_depth,
+                                                                )
+                                                            }
+                                                        }
+                                                    }
+                                                    _ => {
+                                                        panic::parameter_incorrect_format(
+                                                            meta_name.as_str(),
+                                                            &correct_usage_for_max_depth,
+                                                        )
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        Meta::NameValue(named_value) => {
+                                            let lit = &named_value.lit;
+
+                                            match lit {
+                                                Lit::Int(i) => {
+                                                    if max_depth.is_some() {
+                                                        panic::reset_parameter(meta_name.as_str());
+                                                    }
+
+                                                    max_depth = Some(i.base10_parse().unwrap());
+                                                }
+                                                _ => {
+                                                    panic::parameter_incorrect_format(
+                                                        meta_name.as_str(),
+                                                        &correct_usage_for_max_depth,
+                                                    )
+                                                }
+                                            }
+                                        }
+                                        _ => {
+                                            panic::parameter_incorrect_format(
+                                                meta_name.as_str(),
+                                                &correct_usage_for_max_depth,
+                                            )
+                                        }
+                                    }
+                                }
+                                "opaque" | "unsafe_bytes" => {
+                                    if !self.enable_union_format {
+                                        panic::unknown_parameter("Debug", meta_name.as_str());
+                                    }
+
+                                    match meta {
+                                        Meta::Path(_) => {
+                                            if union_format_is_set {
+                                                panic::set_opaque_unsafe_bytes();
+                                            }
+
+                                            union_format_is_set = true;
+
+                                            union_format = if meta_name == "opaque" {
+                                                TypeAttributeUnionFormat::Opaque
+                                            } else {
+                                                TypeAttributeUnionFormat::UnsafeBytes
+                                            };
+                                        }
+                                        _ => {
+                                            if meta_name == "opaque" {
+                                                panic::parameter_incorrect_format(
+                                                    meta_name.as_str(),
+                                                    &correct_usage_for_opaque,
+                                                )
+                                            } else {
+                                                panic::parameter_incorrect_format(
+                                                    meta_name.as_str(),
+                                                    &correct_usage_for_unsafe_bytes,
+                                                )
+                                            }
+                                        }
+                                    }
+                                }
                                 _ => panic::unknown_parameter("Debug", meta_name.as_str()),
                             }
                         }
@@ -468,6 +1115,10 @@ impl TypeAttributeBuilder {
                         }
                     }
                 }
+
+                if name_is_set && style_is_set {
+                    panic::set_name_style();
+                }
             }
             Meta::NameValue(named_value) => {
                 let lit = &named_value.lit;
@@ -505,11 +1156,23 @@ impl TypeAttributeBuilder {
             }
         }
 
+        if let Some(max_depth) = max_depth {
+            if max_depth == 0 || max_depth > 64 {
+                panic::max_depth_out_of_range(max_depth);
+            }
+        }
+
         TypeAttribute {
             flag,
             name,
             named_field,
             bound,
+            non_exhaustive,
+            rename_all,
+            style,
+            with_generics,
+            max_depth,
+            union_format,
         }
     }
 
@@ -558,6 +1221,12 @@ impl TypeAttributeBuilder {
             name: self.name,
             named_field: self.named_field,
             bound: TypeAttributeBound::None,
+            non_exhaustive: self.non_exhaustive,
+            rename_all: TypeAttributeRenameAll::None,
+            style: TypeAttributeStyle::None,
+            with_generics: TypeAttributeWithGenerics::None,
+            max_depth: None,
+            union_format: TypeAttributeUnionFormat::Checked,
         })
     }
 }
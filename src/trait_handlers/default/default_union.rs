@@ -7,7 +7,7 @@ use super::models::{FieldAttributeBuilder, TypeAttributeBuilder};
 use crate::panic;
 use crate::proc_macro2::TokenStream;
 use crate::quote::ToTokens;
-use crate::syn::{Data, DeriveInput, Generics, Lit, Meta};
+use crate::syn::{Data, DeriveInput, Generics, Lit, Meta, Type};
 use crate::Trait;
 
 pub struct DefaultUnionHandler;
@@ -50,7 +50,7 @@ impl TraitHandler for DefaultUnionHandler {
                 None => {
                     let ident = ast.ident.to_string();
 
-                    let (field_name, field_attribute, typ) = {
+                    let (field_name, field_attribute, typ, manually_drop) = {
                         let fields = &data.fields.named;
 
                         if fields.len() == 1 {
@@ -69,6 +69,7 @@ impl TraitHandler for DefaultUnionHandler {
                                 field_name,
                                 field_attribute,
                                 field.ty.clone().into_token_stream().to_string(),
+                                is_manually_drop(&field.ty),
                             )
                         } else {
                             let mut fields_iter = fields.iter();
@@ -120,6 +121,7 @@ impl TraitHandler for DefaultUnionHandler {
                                                 field_name,
                                                 field_attribute,
                                                 field.ty.clone().into_token_stream().to_string(),
+                                                is_manually_drop(&field.ty),
                                             );
                                         }
                                     }
@@ -135,19 +137,27 @@ impl TraitHandler for DefaultUnionHandler {
                         field_name = field_name
                     );
 
+                    // A literal for a `ManuallyDrop<T>` field is a value of `T`, so wrap it.
+                    let wrap = |value: String| {
+                        if manually_drop {
+                            format!("core::mem::ManuallyDrop::new({})", value)
+                        } else {
+                            value
+                        }
+                    };
+
                     match field_attribute.literal {
                         Some(value) => {
                             match &value {
                                 Lit::Str(s) => {
-                                    union_tokens
-                                        .write_fmt(format_args!(
-                                            "core::convert::Into::into({s})",
-                                            s = s.into_token_stream().to_string()
-                                        ))
-                                        .unwrap();
+                                    union_tokens.push_str(&wrap(format!(
+                                        "core::convert::Into::into({s})",
+                                        s = s.into_token_stream()
+                                    )));
                                 }
                                 _ => {
-                                    union_tokens.push_str(&value.into_token_stream().to_string());
+                                    union_tokens
+                                        .push_str(&wrap(value.into_token_stream().to_string()));
                                 }
                             }
                         }
@@ -213,3 +223,13 @@ impl TraitHandler for DefaultUnionHandler {
         }
     }
 }
+
+fn is_manually_drop(ty: &Type) -> bool {
+    if let Type::Path(path) = ty {
+        if let Some(segment) = path.path.segments.last() {
+            return segment.ident == "ManuallyDrop";
+        }
+    }
+
+    false
+}
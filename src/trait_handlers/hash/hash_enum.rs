@@ -1,10 +1,15 @@
 use std::fmt::Write;
 use std::str::FromStr;
 
-use super::super::TraitHandler;
-use super::models::{FieldAttributeBuilder, TypeAttributeBuilder};
+use super::super::{
+    create_by_method, create_ptr_method, create_total_float_method, create_unordered_method,
+    TraitHandler,
+};
+use super::models::{FieldAttributeBuilder, TypeAttributeBuilder, TypeAttributeVariantKey};
 
+use crate::panic;
 use crate::proc_macro2::TokenStream;
+use crate::quote::ToTokens;
 use crate::syn::{Data, DeriveInput, Fields, Generics, Meta};
 use crate::Trait;
 
@@ -20,6 +25,10 @@ impl TraitHandler for HashEnumHandler {
         let type_attribute = TypeAttributeBuilder {
             enable_flag: true,
             enable_bound: true,
+            enable_bytes: false,
+            enable_variant_only: true,
+            enable_variant_key: true,
+            enable_custom_variant_key: false,
         }
         .from_hash_meta(meta);
 
@@ -34,183 +43,253 @@ impl TraitHandler for HashEnumHandler {
         let mut match_tokens = String::from("match self {");
 
         if let Data::Enum(data) = &ast.data {
-            let has_non_unit = {
-                let mut non_unit = false;
+            let mut has_non_unit = false;
+            let mut variant_keys = Vec::new();
+
+            for variant in data.variants.iter() {
+                let variant_attribute = TypeAttributeBuilder {
+                    enable_flag: false,
+                    enable_bound: false,
+                    enable_bytes: false,
+                    enable_variant_only: false,
+                    enable_variant_key: false,
+                    enable_custom_variant_key: true,
+                }
+                .from_attributes(&variant.attrs, traits);
 
-                for variant in data.variants.iter() {
-                    let _ = TypeAttributeBuilder {
-                        enable_flag: false,
-                        enable_bound: false,
+                match &variant.fields {
+                    Fields::Named(_) | Fields::Unnamed(_) => {
+                        has_non_unit = true;
                     }
-                    .from_attributes(&variant.attrs, traits);
+                    _ => (),
+                }
 
-                    match &variant.fields {
-                        Fields::Named(_) | Fields::Unnamed(_) => {
-                            non_unit = true;
+                variant_keys.push(variant_attribute.variant_key);
+            }
 
-                            break;
+            for (index, (variant, variant_key)) in
+                data.variants.iter().zip(variant_keys.iter()).enumerate()
+            {
+                let variant_ident = variant.ident.to_string();
+
+                // A variant can only set a custom key, so `Index` means that it follows the enum.
+                let variant_key = match variant_key {
+                    TypeAttributeVariantKey::Index => &type_attribute.variant_key,
+                    _ => variant_key,
+                };
+
+                let key = match variant_key {
+                    TypeAttributeVariantKey::Index => {
+                        if has_non_unit {
+                            format!("{}", index)
+                        } else {
+                            format!(
+                                "({enum_name}::{variant_ident} as isize)",
+                                enum_name = enum_name,
+                                variant_ident = variant_ident
+                            )
                         }
-                        _ => (),
                     }
-                }
-
-                non_unit
-            };
+                    TypeAttributeVariantKey::Name => format!("\"{}\"", variant_ident),
+                    TypeAttributeVariantKey::Discriminant => {
+                        match variant.discriminant.as_ref() {
+                            Some((_, expression)) => {
+                                format!("(({}) as isize)", expression.into_token_stream())
+                            }
+                            None if !has_non_unit => format!(
+                                "({enum_name}::{variant_ident} as isize)",
+                                enum_name = enum_name,
+                                variant_ident = variant_ident
+                            ),
+                            None => panic::variant_key_without_discriminant(&variant_ident),
+                        }
+                    }
+                    TypeAttributeVariantKey::Custom(key) => key.clone(),
+                };
 
-            if has_non_unit {
-                for (index, variant) in data.variants.iter().enumerate() {
-                    let variant_ident = variant.ident.to_string();
+                let key_statement = format!("core::hash::Hash::hash(&{}, state);", key);
 
-                    match &variant.fields {
-                        Fields::Unit => {
-                            // TODO Unit
-                            match_tokens.write_fmt(format_args!("{enum_name}::{variant_ident} => {{ core::hash::Hash::hash(&{index}, state); }}", enum_name = enum_name, variant_ident = variant_ident, index = index)).unwrap();
-                        }
-                        Fields::Named(fields) => {
-                            // TODO Struct
-                            let mut pattern_tokens = String::new();
-                            let mut block_tokens = String::new();
+                match &variant.fields {
+                    Fields::Unit => {
+                        // TODO Unit
+                        match_tokens.write_fmt(format_args!("{enum_name}::{variant_ident} => {{ {key_statement} }}", enum_name = enum_name, variant_ident = variant_ident, key_statement = key_statement)).unwrap();
+                    }
+                    Fields::Named(fields) => {
+                        // TODO Struct
+                        let mut pattern_tokens = String::new();
+                        let mut block_tokens = String::new();
+
+                        block_tokens.push_str(&key_statement);
+
+                        for field in fields.named.iter() {
+                            let mut field_attribute = FieldAttributeBuilder {
+                                enable_ignore: true,
+                                enable_impl: true,
+                                enable_total: true,
+                                enable_by: true,
+                                enable_ptr: true,
+                                enable_unordered: true,
+                            }
+                            .from_attributes(&field.attrs, traits);
 
-                            block_tokens
-                                .write_fmt(format_args!(
-                                    "core::hash::Hash::hash(&{index}, state);",
-                                    index = index
-                                ))
-                                .unwrap();
+                            if field_attribute.total {
+                                field_attribute.hash_method =
+                                    Some(create_total_float_method(&field.ty, "hash"));
+                            }
 
-                            for field in fields.named.iter() {
-                                let field_attribute = FieldAttributeBuilder {
-                                    enable_ignore: true,
-                                    enable_impl: true,
-                                }
-                                .from_attributes(&field.attrs, traits);
+                            if let Some(by) = field_attribute.by.as_ref() {
+                                field_attribute.hash_method =
+                                    Some(create_by_method(&field.ty, by, "hash"));
+                            }
 
-                                let field_name = field.ident.as_ref().unwrap().to_string();
+                            if field_attribute.ptr {
+                                field_attribute.hash_method =
+                                    Some(create_ptr_method(&field.ty, "hash"));
+                            }
 
-                                if field_attribute.ignore {
-                                    pattern_tokens
-                                        .write_fmt(format_args!(
-                                            "{field_name}: _,",
-                                            field_name = field_name
-                                        ))
-                                        .unwrap();
-                                    continue;
-                                }
+                            if field_attribute.unordered {
+                                field_attribute.hash_method =
+                                    Some(create_unordered_method(&field.ty, "hash"));
+                            }
 
-                                let hash_trait = field_attribute.hash_trait;
-                                let hash_method = field_attribute.hash_method;
+                            let field_name = field.ident.as_ref().unwrap().to_string();
 
+                            if type_attribute.variant_only || field_attribute.ignore {
                                 pattern_tokens
                                     .write_fmt(format_args!(
-                                        "{field_name},",
+                                        "{field_name}: _,",
                                         field_name = field_name
                                     ))
                                     .unwrap();
-
-                                match hash_trait {
-                                    Some(hash_trait) => {
-                                        let hash_method = hash_method.unwrap();
-
-                                        block_tokens
-                                            .write_fmt(format_args!(
-                                                "{hash_trait}::{hash_method}({field_name}, state);",
-                                                hash_trait = hash_trait,
-                                                hash_method = hash_method,
-                                                field_name = field_name
-                                            ))
-                                            .unwrap();
-                                    }
-                                    None => {
-                                        match hash_method {
-                                            Some(hash_method) => {
-                                                block_tokens
-                                                    .write_fmt(format_args!(
-                                                        "{hash_method}({field_name}, state);",
-                                                        hash_method = hash_method,
-                                                        field_name = field_name
-                                                    ))
-                                                    .unwrap();
-                                            }
-                                            None => {
-                                                block_tokens.write_fmt(format_args!("core::hash::Hash::hash({field_name}, state);", field_name = field_name)).unwrap();
-                                            }
-                                        }
-                                    }
-                                }
+                                continue;
                             }
 
-                            match_tokens.write_fmt(format_args!("{enum_name}::{variant_ident} {{ {pattern_tokens} }} => {{ {block_tokens} }}", enum_name = enum_name, variant_ident = variant_ident, pattern_tokens = pattern_tokens, block_tokens = block_tokens)).unwrap();
-                        }
-                        Fields::Unnamed(fields) => {
-                            // TODO Tuple
-                            let mut pattern_tokens = String::new();
-                            let mut block_tokens = String::new();
+                            let hash_trait = field_attribute.hash_trait;
+                            let hash_method = field_attribute.hash_method;
 
-                            block_tokens
+                            pattern_tokens
                                 .write_fmt(format_args!(
-                                    "core::hash::Hash::hash(&{index}, state);",
-                                    index = index
+                                    "{field_name},",
+                                    field_name = field_name
                                 ))
                                 .unwrap();
 
-                            for (index, field) in fields.unnamed.iter().enumerate() {
-                                let field_attribute = FieldAttributeBuilder {
-                                    enable_ignore: true,
-                                    enable_impl: true,
-                                }
-                                .from_attributes(&field.attrs, traits);
+                            match hash_trait {
+                                Some(hash_trait) => {
+                                    let hash_method = hash_method.unwrap();
 
-                                if field_attribute.ignore {
-                                    pattern_tokens.push_str("_,");
-                                    continue;
+                                    block_tokens
+                                        .write_fmt(format_args!(
+                                            "{hash_trait}::{hash_method}({field_name}, state);",
+                                            hash_trait = hash_trait,
+                                            hash_method = hash_method,
+                                            field_name = field_name
+                                        ))
+                                        .unwrap();
                                 }
+                                None => {
+                                    match hash_method {
+                                        Some(hash_method) => {
+                                            block_tokens
+                                                .write_fmt(format_args!(
+                                                    "{hash_method}({field_name}, state);",
+                                                    hash_method = hash_method,
+                                                    field_name = field_name
+                                                ))
+                                                .unwrap();
+                                        }
+                                        None => {
+                                            block_tokens.write_fmt(format_args!("core::hash::Hash::hash({field_name}, state);", field_name = field_name)).unwrap();
+                                        }
+                                    }
+                                }
+                            }
+                        }
 
-                                let hash_trait = field_attribute.hash_trait;
-                                let hash_method = field_attribute.hash_method;
+                        match_tokens.write_fmt(format_args!("{enum_name}::{variant_ident} {{ {pattern_tokens} }} => {{ {block_tokens} }}", enum_name = enum_name, variant_ident = variant_ident, pattern_tokens = pattern_tokens, block_tokens = block_tokens)).unwrap();
+                    }
+                    Fields::Unnamed(fields) => {
+                        // TODO Tuple
+                        let mut pattern_tokens = String::new();
+                        let mut block_tokens = String::new();
+
+                        block_tokens.push_str(&key_statement);
+
+                        for (index, field) in fields.unnamed.iter().enumerate() {
+                            let mut field_attribute = FieldAttributeBuilder {
+                                enable_ignore: true,
+                                enable_impl: true,
+                                enable_total: true,
+                                enable_by: true,
+                                enable_ptr: true,
+                                enable_unordered: true,
+                            }
+                            .from_attributes(&field.attrs, traits);
 
-                                let field_name = format!("{}", index);
+                            if field_attribute.total {
+                                field_attribute.hash_method =
+                                    Some(create_total_float_method(&field.ty, "hash"));
+                            }
 
-                                pattern_tokens
-                                    .write_fmt(format_args!(
-                                        "_{field_name},",
-                                        field_name = field_name
-                                    ))
-                                    .unwrap();
+                            if let Some(by) = field_attribute.by.as_ref() {
+                                field_attribute.hash_method =
+                                    Some(create_by_method(&field.ty, by, "hash"));
+                            }
 
-                                match hash_trait {
-                                    Some(hash_trait) => {
-                                        let hash_method = hash_method.unwrap();
+                            if field_attribute.ptr {
+                                field_attribute.hash_method =
+                                    Some(create_ptr_method(&field.ty, "hash"));
+                            }
 
-                                        block_tokens.write_fmt(format_args!("{hash_trait}::{hash_method}(_{field_name}, state);", hash_trait = hash_trait, hash_method = hash_method, field_name = field_name)).unwrap();
-                                    }
-                                    None => {
-                                        match hash_method {
-                                            Some(hash_method) => {
-                                                block_tokens
-                                                    .write_fmt(format_args!(
-                                                        "{hash_method}(_{field_name}, state);",
-                                                        hash_method = hash_method,
-                                                        field_name = field_name
-                                                    ))
-                                                    .unwrap();
-                                            }
-                                            None => {
-                                                block_tokens.write_fmt(format_args!("core::hash::Hash::hash(_{field_name}, state);", field_name = field_name)).unwrap();
-                                            }
+                            if field_attribute.unordered {
+                                field_attribute.hash_method =
+                                    Some(create_unordered_method(&field.ty, "hash"));
+                            }
+
+                            if type_attribute.variant_only || field_attribute.ignore {
+                                pattern_tokens.push_str("_,");
+                                continue;
+                            }
+
+                            let hash_trait = field_attribute.hash_trait;
+                            let hash_method = field_attribute.hash_method;
+
+                            let field_name = format!("{}", index);
+
+                            pattern_tokens
+                                .write_fmt(format_args!(
+                                    "_{field_name},",
+                                    field_name = field_name
+                                ))
+                                .unwrap();
+
+                            match hash_trait {
+                                Some(hash_trait) => {
+                                    let hash_method = hash_method.unwrap();
+
+                                    block_tokens.write_fmt(format_args!("{hash_trait}::{hash_method}(_{field_name}, state);", hash_trait = hash_trait, hash_method = hash_method, field_name = field_name)).unwrap();
+                                }
+                                None => {
+                                    match hash_method {
+                                        Some(hash_method) => {
+                                            block_tokens
+                                                .write_fmt(format_args!(
+                                                    "{hash_method}(_{field_name}, state);",
+                                                    hash_method = hash_method,
+                                                    field_name = field_name
+                                                ))
+                                                .unwrap();
+                                        }
+                                        None => {
+                                            block_tokens.write_fmt(format_args!("core::hash::Hash::hash(_{field_name}, state);", field_name = field_name)).unwrap();
                                         }
                                     }
                                 }
                             }
-
-                            match_tokens.write_fmt(format_args!("{enum_name}::{variant_ident}( {pattern_tokens} ) => {{ {block_tokens} }}", enum_name = enum_name, variant_ident = variant_ident, pattern_tokens = pattern_tokens, block_tokens = block_tokens)).unwrap();
                         }
-                    }
-                }
-            } else {
-                for variant in data.variants.iter() {
-                    let variant_ident = variant.ident.to_string();
 
-                    match_tokens.write_fmt(format_args!("{enum_name}::{variant_ident} => {{ core::hash::Hash::hash(&({enum_name}::{variant_ident} as isize), state); }}", enum_name = enum_name, variant_ident = variant_ident)).unwrap();
+                        match_tokens.write_fmt(format_args!("{enum_name}::{variant_ident}( {pattern_tokens} ) => {{ {block_tokens} }}", enum_name = enum_name, variant_ident = variant_ident, pattern_tokens = pattern_tokens, block_tokens = block_tokens)).unwrap();
+                    }
                 }
             }
         }
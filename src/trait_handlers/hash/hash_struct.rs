@@ -1,6 +1,9 @@
 use std::str::FromStr;
 
-use super::super::TraitHandler;
+use super::super::{
+    create_by_method, create_ptr_method, create_total_float_method, create_unordered_method,
+    TraitHandler,
+};
 use super::models::{FieldAttributeBuilder, TypeAttributeBuilder};
 
 use crate::proc_macro2::TokenStream;
@@ -19,6 +22,10 @@ impl TraitHandler for HashStructHandler {
         let type_attribute = TypeAttributeBuilder {
             enable_flag: true,
             enable_bound: true,
+            enable_bytes: false,
+            enable_variant_only: false,
+            enable_variant_key: false,
+            enable_custom_variant_key: false,
         }
         .from_hash_meta(meta);
 
@@ -30,12 +37,36 @@ impl TraitHandler for HashStructHandler {
 
         if let Data::Struct(data) = &ast.data {
             for (index, field) in data.fields.iter().enumerate() {
-                let field_attribute = FieldAttributeBuilder {
+                let mut field_attribute = FieldAttributeBuilder {
                     enable_ignore: true,
                     enable_impl: true,
+                    enable_total: true,
+                    enable_by: true,
+                    enable_ptr: true,
+                    enable_unordered: true,
                 }
                 .from_attributes(&field.attrs, traits);
 
+                if field_attribute.total {
+                    field_attribute.hash_method =
+                        Some(create_total_float_method(&field.ty, "hash"));
+                }
+
+                if let Some(by) = field_attribute.by.as_ref() {
+                    field_attribute.hash_method =
+                        Some(create_by_method(&field.ty, by, "hash"));
+                }
+
+                if field_attribute.ptr {
+                    field_attribute.hash_method =
+                        Some(create_ptr_method(&field.ty, "hash"));
+                }
+
+                if field_attribute.unordered {
+                    field_attribute.hash_method =
+                        Some(create_unordered_method(&field.ty, "hash"));
+                }
+
                 if field_attribute.ignore {
                     continue;
                 }
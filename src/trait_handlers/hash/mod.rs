@@ -2,16 +2,17 @@ mod models;
 
 mod hash_enum;
 mod hash_struct;
+mod hash_union;
 
 use super::TraitHandler;
 
-use crate::panic;
 use crate::proc_macro2::TokenStream;
 use crate::syn::{Data, DeriveInput, Meta};
 use crate::Trait;
 
 use hash_enum::HashEnumHandler;
 use hash_struct::HashStructHandler;
+use hash_union::HashUnionHandler;
 
 pub struct HashHandler;
 
@@ -29,7 +30,9 @@ impl TraitHandler for HashHandler {
             Data::Enum(_) => {
                 HashEnumHandler::trait_meta_handler(ast, tokens, traits, meta);
             }
-            Data::Union(_) => panic::trait_not_support_union(Trait::Hash),
+            Data::Union(_) => {
+                HashUnionHandler::trait_meta_handler(ast, tokens, traits, meta);
+            }
         }
     }
 }
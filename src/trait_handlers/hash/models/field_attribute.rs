@@ -1,4 +1,4 @@
-use super::super::super::create_path_string_from_lit_str;
+use super::super::super::{create_expr_string_from_lit_str, create_path_string_from_lit_str};
 
 use crate::panic;
 use crate::quote::ToTokens;
@@ -10,18 +10,30 @@ pub struct FieldAttribute {
     pub ignore: bool,
     pub hash_method: Option<String>,
     pub hash_trait: Option<String>,
+    pub total: bool,
+    pub by: Option<String>,
+    pub ptr: bool,
+    pub unordered: bool,
 }
 
 #[derive(Debug, Clone)]
 pub struct FieldAttributeBuilder {
     pub enable_ignore: bool,
     pub enable_impl: bool,
+    pub enable_total: bool,
+    pub enable_by: bool,
+    pub enable_ptr: bool,
+    pub enable_unordered: bool,
 }
 
 impl FieldAttributeBuilder {
     #[allow(clippy::cognitive_complexity)]
     pub fn from_hash_meta(&self, meta: &Meta) -> FieldAttribute {
         let mut ignore = false;
+        let mut total = false;
+        let mut by = None;
+        let mut ptr = false;
+        let mut unordered = false;
 
         let mut hash_method = None;
         let mut hash_trait = None;
@@ -43,6 +55,33 @@ impl FieldAttributeBuilder {
             usage
         };
 
+        let correct_usage_for_total = {
+            let usage = vec![stringify!(#[educe(Hash(total))])];
+
+            usage
+        };
+
+        let correct_usage_for_ptr = {
+            let usage = vec![stringify!(#[educe(Hash(ptr))])];
+
+            usage
+        };
+
+        let correct_usage_for_unordered = {
+            let usage = vec![stringify!(#[educe(Hash(unordered))])];
+
+            usage
+        };
+
+        let correct_usage_for_by = {
+            let usage = vec![
+                stringify!(#[educe(Hash(by = "expression"))]),
+                stringify!(#[educe(Hash(by("expression")))]),
+            ];
+
+            usage
+        };
+
         let correct_usage_for_impl = {
             let usage = vec![
                 stringify!(#[educe(Hash(method = "path_to_method"))]),
@@ -59,6 +98,9 @@ impl FieldAttributeBuilder {
         match meta {
             Meta::List(list) => {
                 let mut ignore_is_set = false;
+                let mut total_is_set = false;
+                let mut ptr_is_set = false;
+                let mut unordered_is_set = false;
 
                 for p in list.nested.iter() {
                     match p {
@@ -251,6 +293,143 @@ impl FieldAttributeBuilder {
                                         }
                                     }
                                 }
+                                "total" => {
+                                    if !self.enable_total {
+                                        panic::unknown_parameter("Hash", meta_name.as_str());
+                                    }
+
+                                    match meta {
+                                        Meta::Path(_) => {
+                                            if total_is_set {
+                                                panic::reset_parameter(meta_name.as_str());
+                                            }
+
+                                            total_is_set = true;
+
+                                            total = true;
+                                        }
+                                        _ => {
+                                            panic::parameter_incorrect_format(
+                                                meta_name.as_str(),
+                                                &correct_usage_for_total,
+                                            )
+                                        }
+                                    }
+                                }
+                                "ptr" => {
+                                    if !self.enable_ptr {
+                                        panic::unknown_parameter("Hash", meta_name.as_str());
+                                    }
+
+                                    match meta {
+                                        Meta::Path(_) => {
+                                            if ptr_is_set {
+                                                panic::reset_parameter(meta_name.as_str());
+                                            }
+
+                                            ptr_is_set = true;
+
+                                            ptr = true;
+                                        }
+                                        _ => {
+                                            panic::parameter_incorrect_format(
+                                                meta_name.as_str(),
+                                                &correct_usage_for_ptr,
+                                            )
+                                        }
+                                    }
+                                }
+                                "unordered" => {
+                                    if !self.enable_unordered {
+                                        panic::unknown_parameter("Hash", meta_name.as_str());
+                                    }
+
+                                    match meta {
+                                        Meta::Path(_) => {
+                                            if unordered_is_set {
+                                                panic::reset_parameter(meta_name.as_str());
+                                            }
+
+                                            unordered_is_set = true;
+
+                                            unordered = true;
+                                        }
+                                        _ => {
+                                            panic::parameter_incorrect_format(
+                                                meta_name.as_str(),
+                                                &correct_usage_for_unordered,
+                                            )
+                                        }
+                                    }
+                                }
+                                "by" => {
+                                    if !self.enable_by {
+                                        panic::unknown_parameter("Hash", meta_name.as_str());
+                                    }
+
+                                    match meta {
+                                        Meta::List(list) => {
+                                            for p in list.nested.iter() {
+                                                match p {
+                                                    NestedMeta::Lit(Lit::Str(s)) => {
+                                                        if by.is_some() {
+                                                            panic::reset_parameter(
+                                                                meta_name.as_str(),
+                                                            );
+                                                        }
+
+                                                        let s = create_expr_string_from_lit_str(s);
+
+                                                        if let Some(s) = s {
+                                                            by = Some(s);
+                                                        } else {
+                                                            panic::empty_parameter(
+                                                                meta_name.as_str(),
+                                                            );
+                                                        }
+                                                    }
+                                                    _ => {
+                                                        panic::parameter_incorrect_format(
+                                                            meta_name.as_str(),
+                                                            &correct_usage_for_by,
+                                                        )
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        Meta::NameValue(named_value) => {
+                                            let lit = &named_value.lit;
+
+                                            match lit {
+                                                Lit::Str(s) => {
+                                                    if by.is_some() {
+                                                        panic::reset_parameter(meta_name.as_str());
+                                                    }
+
+                                                    let s = create_expr_string_from_lit_str(s);
+
+                                                    if let Some(s) = s {
+                                                        by = Some(s);
+                                                    } else {
+                                                        panic::empty_parameter(meta_name.as_str());
+                                                    }
+                                                }
+                                                _ => {
+                                                    panic::parameter_incorrect_format(
+                                                        meta_name.as_str(),
+                                                        &correct_usage_for_by,
+                                                    )
+                                                }
+                                            }
+                                        }
+                                        _ => {
+                                            panic::parameter_incorrect_format(
+                                                meta_name.as_str(),
+                                                &correct_usage_for_by,
+                                            )
+                                        }
+                                    }
+                                }
                                 _ => panic::unknown_parameter("Hash", meta_name.as_str()),
                             }
                         }
@@ -266,6 +445,37 @@ impl FieldAttributeBuilder {
             _ => panic::attribute_incorrect_format("Hash", &correct_usage_for_hash_attribute),
         }
 
+        if total && (hash_method.is_some() || hash_trait.is_some()) {
+            panic::set_total_impl();
+        }
+
+        if unordered
+            && (total
+                || ptr
+                || by.is_some()
+                || hash_method.is_some()
+                || hash_trait.is_some())
+        {
+            panic::set_unordered_impl();
+        }
+
+        if ptr
+            && (total
+                || by.is_some()
+                || hash_method.is_some()
+                || hash_trait.is_some())
+        {
+            panic::set_ptr_impl();
+        }
+
+        if by.is_some()
+            && (total
+                || hash_method.is_some()
+                || hash_trait.is_some())
+        {
+            panic::set_by_impl();
+        }
+
         if hash_trait.is_some() && hash_method.is_none() {
             hash_method = Some("hash".to_string());
         }
@@ -274,6 +484,10 @@ impl FieldAttributeBuilder {
             ignore,
             hash_method,
             hash_trait,
+            total,
+            by,
+            ptr,
+            unordered,
         }
     }
 
@@ -321,6 +535,10 @@ impl FieldAttributeBuilder {
             ignore: false,
             hash_method: None,
             hash_trait: None,
+            total: false,
+            by: None,
+            ptr: false,
+            unordered: false,
         })
     }
 }
@@ -35,22 +35,70 @@ impl TypeAttributeBound {
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypeAttributeVariantKey {
+    Index,
+    Name,
+    Discriminant,
+    Custom(String),
+}
+
+impl TypeAttributeVariantKey {
+    fn from_lit(lit: &Lit, custom: bool) -> Option<TypeAttributeVariantKey> {
+        if custom {
+            match lit {
+                Lit::Str(s) => {
+                    Some(TypeAttributeVariantKey::Custom(s.into_token_stream().to_string()))
+                }
+                Lit::Int(i) => {
+                    let value: isize = i.base10_parse().ok()?;
+
+                    Some(TypeAttributeVariantKey::Custom(format!("{}isize", value)))
+                }
+                _ => None,
+            }
+        } else {
+            match lit {
+                Lit::Str(s) => {
+                    match s.value().as_str() {
+                        "index" => Some(TypeAttributeVariantKey::Index),
+                        "name" => Some(TypeAttributeVariantKey::Name),
+                        "discriminant" => Some(TypeAttributeVariantKey::Discriminant),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            }
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct TypeAttribute {
     pub flag: bool,
     pub bound: TypeAttributeBound,
+    pub bytes: bool,
+    pub variant_only: bool,
+    pub variant_key: TypeAttributeVariantKey,
 }
 
 #[derive(Debug, Clone)]
 pub struct TypeAttributeBuilder {
     pub enable_flag: bool,
     pub enable_bound: bool,
+    pub enable_bytes: bool,
+    pub enable_variant_only: bool,
+    pub enable_variant_key: bool,
+    pub enable_custom_variant_key: bool,
 }
 
 impl TypeAttributeBuilder {
     pub fn from_hash_meta(&self, meta: &Meta) -> TypeAttribute {
         let mut flag = false;
         let mut bound = TypeAttributeBound::None;
+        let mut bytes = false;
+        let mut variant_only = false;
+        let mut variant_key = TypeAttributeVariantKey::Index;
 
         let correct_usage_for_hash_attribute = {
             let mut usage = vec![];
@@ -72,9 +120,41 @@ impl TypeAttributeBuilder {
             usage
         };
 
+        let correct_usage_for_variant_key = {
+            let mut usage = vec![];
+
+            if self.enable_variant_key {
+                usage.push(stringify!(#[educe(Hash(variant_key = "index"))]));
+                usage.push(stringify!(#[educe(Hash(variant_key = "name"))]));
+                usage.push(stringify!(#[educe(Hash(variant_key = "discriminant"))]));
+            }
+
+            if self.enable_custom_variant_key {
+                usage.push(stringify!(#[educe(Hash(variant_key = "key"))]));
+                usage.push(stringify!(#[educe(Hash(variant_key = integer))]));
+            }
+
+            usage
+        };
+
+        let correct_usage_for_variant_only = {
+            let usage = vec![stringify!(#[educe(Hash(variant_only))])];
+
+            usage
+        };
+
+        let correct_usage_for_bytes = {
+            let usage = vec![stringify!(#[educe(Hash(bytes))])];
+
+            usage
+        };
+
         match meta {
             Meta::List(list) => {
                 let mut bound_is_set = false;
+                let mut bytes_is_set = false;
+                let mut variant_only_is_set = false;
+                let mut variant_key_is_set = false;
 
                 for p in list.nested.iter() {
                     match p {
@@ -180,6 +260,97 @@ impl TypeAttributeBuilder {
                                         }
                                     }
                                 }
+                                "variant_key" => {
+                                    if !self.enable_variant_key && !self.enable_custom_variant_key {
+                                        panic::unknown_parameter("Hash", meta_name.as_str());
+                                    }
+
+                                    let lit = match meta {
+                                        Meta::List(list) if list.nested.len() == 1 => {
+                                            match &list.nested[0] {
+                                                NestedMeta::Lit(lit) => lit,
+                                                _ => {
+                                                    panic::parameter_incorrect_format(
+                                                        meta_name.as_str(),
+                                                        &correct_usage_for_variant_key,
+                                                    )
+                                                }
+                                            }
+                                        }
+                                        Meta::NameValue(named_value) => &named_value.lit,
+                                        _ => {
+                                            panic::parameter_incorrect_format(
+                                                meta_name.as_str(),
+                                                &correct_usage_for_variant_key,
+                                            )
+                                        }
+                                    };
+
+                                    if variant_key_is_set {
+                                        panic::reset_parameter(meta_name.as_str());
+                                    }
+
+                                    variant_key_is_set = true;
+
+                                    variant_key = match TypeAttributeVariantKey::from_lit(
+                                        lit,
+                                        self.enable_custom_variant_key,
+                                    ) {
+                                        Some(variant_key) => variant_key,
+                                        None => {
+                                            panic::parameter_incorrect_format(
+                                                meta_name.as_str(),
+                                                &correct_usage_for_variant_key,
+                                            )
+                                        }
+                                    };
+                                }
+                                "variant_only" => {
+                                    if !self.enable_variant_only {
+                                        panic::unknown_parameter("Hash", meta_name.as_str());
+                                    }
+
+                                    match meta {
+                                        Meta::Path(_) => {
+                                            if variant_only_is_set {
+                                                panic::reset_parameter(meta_name.as_str());
+                                            }
+
+                                            variant_only_is_set = true;
+
+                                            variant_only = true;
+                                        }
+                                        _ => {
+                                            panic::parameter_incorrect_format(
+                                                meta_name.as_str(),
+                                                &correct_usage_for_variant_only,
+                                            )
+                                        }
+                                    }
+                                }
+                                "bytes" => {
+                                    if !self.enable_bytes {
+                                        panic::unknown_parameter("Hash", meta_name.as_str());
+                                    }
+
+                                    match meta {
+                                        Meta::Path(_) => {
+                                            if bytes_is_set {
+                                                panic::reset_parameter(meta_name.as_str());
+                                            }
+
+                                            bytes_is_set = true;
+
+                                            bytes = true;
+                                        }
+                                        _ => {
+                                            panic::parameter_incorrect_format(
+                                                meta_name.as_str(),
+                                                &correct_usage_for_bytes,
+                                            )
+                                        }
+                                    }
+                                }
                                 _ => panic::unknown_parameter("Hash", meta_name.as_str()),
                             }
                         }
@@ -207,6 +378,9 @@ impl TypeAttributeBuilder {
         TypeAttribute {
             flag,
             bound,
+            bytes,
+            variant_only,
+            variant_key,
         }
     }
 
@@ -253,6 +427,9 @@ impl TypeAttributeBuilder {
         result.unwrap_or(TypeAttribute {
             flag: false,
             bound: TypeAttributeBound::None,
+            bytes: false,
+            variant_only: false,
+            variant_key: TypeAttributeVariantKey::Index,
         })
     }
 }
@@ -28,9 +28,10 @@ use std::str::FromStr;
 use crate::proc_macro2::TokenStream;
 use crate::quote::ToTokens;
 use crate::syn::{
-    self, punctuated::Punctuated, token::Comma, DeriveInput, Expr, GenericParam, LitStr, Meta,
-    Path, WhereClause, WherePredicate,
+    self, punctuated::Punctuated, token::Comma, Data, DeriveInput, Expr, Fields, GenericParam,
+    Lit, LitStr, Meta, NestedMeta, Path, Type, WhereClause, WherePredicate,
 };
+use crate::panic;
 use crate::Trait;
 
 pub trait TraitHandler {
@@ -82,6 +83,29 @@ pub fn create_expr_string_from_lit_str(s: &LitStr) -> Option<String> {
     create_expr_from_lit_str(s).map(|expr| expr.into_token_stream().to_string().replace(" ", ""))
 }
 
+#[inline]
+pub fn create_type_from_lit_str(s: &LitStr) -> Option<Type> {
+    let s = s.value();
+
+    let s = s.trim();
+
+    if s.is_empty() {
+        None
+    } else {
+        let tokens = TokenStream::from_str(s).unwrap();
+
+        Some(syn::parse2(tokens).unwrap())
+    }
+}
+
+// A tuple struct with only one field is compared with the right-hand side itself.
+pub fn create_rhs_value(fields: &Fields, field_name: &str) -> String {
+    match fields {
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => String::from("other"),
+        _ => format!("&other.{}", field_name),
+    }
+}
+
 #[inline]
 pub fn create_where_predicates_from_lit_str(
     s: &LitStr,
@@ -120,3 +144,224 @@ pub fn create_where_predicates_from_generic_parameters(
 
     where_predicates
 }
+
+#[inline]
+pub fn get_union_tag(meta: &Meta) -> Option<String> {
+    let lit = match meta {
+        Meta::NameValue(named_value) => &named_value.lit,
+        Meta::List(list) if list.nested.len() == 1 => {
+            match &list.nested[0] {
+                NestedMeta::Lit(lit) => lit,
+                _ => return None,
+            }
+        }
+        _ => return None,
+    };
+
+    match lit {
+        Lit::Str(s) => create_path_string_from_lit_str(s),
+        _ => None,
+    }
+}
+
+pub fn find_union_tag(ast: &DeriveInput) -> Option<String> {
+    if let Data::Union(_) = &ast.data {
+        for attr in ast.attrs.iter() {
+            if !attr.path.is_ident("educe") {
+                continue;
+            }
+
+            if let Ok(Meta::List(list)) = attr.parse_meta() {
+                for p in list.nested.iter() {
+                    if let NestedMeta::Meta(meta) = p {
+                        if meta.path().is_ident("union_tag") {
+                            return get_union_tag(meta);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    None
+}
+
+pub fn create_union_bytes_check(ast: &DeriveInput) -> TokenStream {
+    let mut tokens = TokenStream::new();
+
+    if let Data::Union(data) = &ast.data {
+        if has_repr_align(ast) {
+            panic::union_field_may_have_padding(&ast.ident.to_string());
+        }
+
+        for field in data.fields.named.iter() {
+            if !is_padding_free(&field.ty) {
+                panic::union_field_may_have_padding(&field.ident.as_ref().unwrap().to_string());
+            }
+        }
+
+        let mut fields = data.fields.named.iter();
+
+        let first_ty = &fields.next().unwrap().ty;
+
+        for field in fields {
+            let ty = &field.ty;
+
+            tokens.extend(quote!(
+                const _: () = assert!(
+                    core::mem::size_of::<#ty>() == core::mem::size_of::<#first_ty>(),
+                    "All fields of the union must have the same size to be treated as bytes."
+                );
+            ));
+        }
+    }
+
+    tokens
+}
+
+fn is_padding_free(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => {
+            if path.qself.is_some() || path.path.segments.len() != 1 {
+                return false;
+            }
+
+            let segment = &path.path.segments[0];
+
+            if !segment.arguments.is_empty() {
+                return false;
+            }
+
+            matches!(
+                segment.ident.to_string().as_str(),
+                "u8" | "u16"
+                    | "u32"
+                    | "u64"
+                    | "u128"
+                    | "usize"
+                    | "i8"
+                    | "i16"
+                    | "i32"
+                    | "i64"
+                    | "i128"
+                    | "isize"
+                    | "f32"
+                    | "f64"
+                    | "bool"
+                    | "char"
+            )
+        }
+        Type::Array(array) => is_padding_free(&array.elem),
+        Type::Paren(paren) => is_padding_free(&paren.elem),
+        Type::Group(group) => is_padding_free(&group.elem),
+        _ => false,
+    }
+}
+
+fn has_repr_align(ast: &DeriveInput) -> bool {
+    for attr in ast.attrs.iter() {
+        if let Ok(Meta::List(list)) = attr.parse_meta() {
+            if !list.path.is_ident("repr") {
+                continue;
+            }
+
+            for p in list.nested.iter() {
+                if let NestedMeta::Meta(meta) = p {
+                    if meta.path().is_ident("align") {
+                        return true;
+                    }
+                }
+            }
+        }
+    }
+
+    false
+}
+
+pub fn create_total_float_method(ty: &Type, method: &str) -> String {
+    let float = match ty {
+        Type::Path(path) if path.qself.is_none() => {
+            match path.path.segments.last() {
+                Some(segment) if segment.arguments.is_empty() => segment.ident.to_string(),
+                _ => panic::total_non_float_field(),
+            }
+        }
+        _ => panic::total_non_float_field(),
+    };
+
+    if float != "f32" && float != "f64" {
+        panic::total_non_float_field();
+    }
+
+    let normalize = format!(
+        "let n = |v: {float}| if v.is_nan() {{ {float}::NAN }} else if v == 0.0 {{ 0.0 }} else {{ v }};",
+        float = float
+    );
+
+    match method {
+        "eq" => format!("(|a: &{float}, b: &{float}| {{ {normalize} n(*a).to_bits() == n(*b).to_bits() }})", float = float, normalize = normalize),
+        "partial_cmp" => format!("(|a: &{float}, b: &{float}| {{ {normalize} Some({float}::total_cmp(&n(*a), &n(*b))) }})", float = float, normalize = normalize),
+        "cmp" => format!("(|a: &{float}, b: &{float}| {{ {normalize} {float}::total_cmp(&n(*a), &n(*b)) }})", float = float, normalize = normalize),
+        "hash" => format!("(|v: &{float}, state: &mut H| {{ {normalize} core::hash::Hash::hash(&n(*v).to_bits(), state) }})", float = float, normalize = normalize),
+        _ => unreachable!(),
+    }
+}
+
+pub fn create_by_method(ty: &Type, by: &str, method: &str) -> String {
+    let ty = ty.into_token_stream().to_string();
+
+    match method {
+        "eq" => format!("(|a: &{ty}, b: &{ty}| core::cmp::PartialEq::eq(&({by})(a), &({by})(b)))", ty = ty, by = by),
+        "partial_cmp" => format!("(|a: &{ty}, b: &{ty}| core::cmp::PartialOrd::partial_cmp(&({by})(a), &({by})(b)))", ty = ty, by = by),
+        "cmp" => format!("(|a: &{ty}, b: &{ty}| core::cmp::Ord::cmp(&({by})(a), &({by})(b)))", ty = ty, by = by),
+        "hash" => format!("(|v: &{ty}, state: &mut H| core::hash::Hash::hash(&({by})(v), state))", ty = ty, by = by),
+        _ => unreachable!(),
+    }
+}
+
+pub fn create_none_ordering_statement(
+    self_value: &str,
+    other_value: &str,
+    statement: &str,
+    none_last: bool,
+    method: &str,
+) -> String {
+    let (some_none, none_some) = if none_last {
+        ("core::cmp::Ordering::Less", "core::cmp::Ordering::Greater")
+    } else {
+        ("core::cmp::Ordering::Greater", "core::cmp::Ordering::Less")
+    };
+
+    let (some_none, none_some) = match method {
+        "cmp" => (some_none.to_string(), none_some.to_string()),
+        "partial_cmp" => (format!("Some({})", some_none), format!("Some({})", none_some)),
+        _ => unreachable!(),
+    };
+
+    format!("match ({self_value}, {other_value}) {{ (Some(___self), Some(___other)) => {{ {statement} }} (Some(_), None) => {{ return {some_none}; }} (None, Some(_)) => {{ return {none_some}; }} (None, None) => () }}", self_value = self_value, other_value = other_value, statement = statement, some_none = some_none, none_some = none_some)
+}
+
+pub fn create_ptr_method(ty: &Type, method: &str) -> String {
+    let address = match ty {
+        Type::Ptr(_) => "*v as *const () as usize",
+        _ => "&**v as *const _ as *const () as usize",
+    };
+
+    let by = format!("|v: &{ty}| {address}", ty = ty.into_token_stream(), address = address);
+
+    create_by_method(ty, &by, method)
+}
+
+pub fn create_unordered_method(ty: &Type, method: &str) -> String {
+    let ty = ty.into_token_stream().to_string();
+
+    match method {
+        "eq" => format!("(|a: &{ty}, b: &{ty}| {{ let count = |v: &{ty}, x| core::iter::IntoIterator::into_iter(v).filter(|y| *y == x).count(); core::iter::Iterator::count(core::iter::IntoIterator::into_iter(a)) == core::iter::Iterator::count(core::iter::IntoIterator::into_iter(b)) && core::iter::IntoIterator::into_iter(a).all(|x| count(a, x) == count(b, x)) }})", ty = ty),
+        "hash" => format!("(|v: &{ty}, state: &mut H| {{ let key = core::hash::Hasher::finish(state); let mut len = 0usize; let mut sum = 0u64; for x in core::iter::IntoIterator::into_iter(v) {{ #[allow(deprecated)] let mut hasher = core::hash::SipHasher::new_with_keys(key, !key); core::hash::Hash::hash(&x, &mut hasher); sum = sum.wrapping_add(core::hash::Hasher::finish(&hasher)); len += 1; }} core::hash::Hash::hash(&len, state); core::hash::Hash::hash(&sum, state); }})", ty = ty),
+        _ => unreachable!(),
+    }
+}
+
+pub fn create_rank_uniqueness_check(values: &[String]) -> String {
+    format!("const _: () = {{ let ranks: [isize; {len}] = [{values}]; let mut i = 0; while i < {len} {{ let mut j = i + 1; while j < {len} {{ if ranks[i] == ranks[j] {{ panic!(\"The rank of each variant must be unique.\"); }} j += 1; }} i += 1; }} }};", len = values.len(), values = values.join(", "))
+}
@@ -2,16 +2,17 @@ mod models;
 
 mod ord_enum;
 mod ord_struct;
+mod ord_union;
 
 use super::TraitHandler;
 
-use crate::panic;
 use crate::proc_macro2::TokenStream;
 use crate::syn::{Data, DeriveInput, Meta};
 use crate::Trait;
 
 use ord_enum::OrdEnumHandler;
 use ord_struct::OrdStructHandler;
+use ord_union::OrdUnionHandler;
 
 pub struct OrdHandler;
 
@@ -29,7 +30,9 @@ impl TraitHandler for OrdHandler {
             Data::Enum(_) => {
                 OrdEnumHandler::trait_meta_handler(ast, tokens, traits, meta);
             }
-            Data::Union(_) => panic::trait_not_support_union(Trait::Ord),
+            Data::Union(_) => {
+                OrdUnionHandler::trait_meta_handler(ast, tokens, traits, meta);
+            }
         }
     }
 }
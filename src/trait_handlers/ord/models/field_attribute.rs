@@ -1,16 +1,38 @@
-use super::super::super::create_path_string_from_lit_str;
+use super::super::super::{create_expr_string_from_lit_str, create_path_string_from_lit_str};
 
 use crate::panic;
 use crate::quote::ToTokens;
 use crate::syn::{Attribute, Lit, Meta, NestedMeta};
 use crate::Trait;
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FieldAttributeNone {
+    Default,
+    First,
+    Last,
+}
+
+impl FieldAttributeNone {
+    pub fn from_str(s: &str) -> Option<FieldAttributeNone> {
+        match s {
+            "first" => Some(FieldAttributeNone::First),
+            "last" => Some(FieldAttributeNone::Last),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct FieldAttribute {
     pub ignore: bool,
     pub compare_method: Option<String>,
     pub compare_trait: Option<String>,
     pub rank: isize,
+    pub reverse: bool,
+    pub none: FieldAttributeNone,
+    pub total: bool,
+    pub by: Option<String>,
+    pub ptr: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -19,18 +41,30 @@ pub struct FieldAttributeBuilder {
     pub enable_impl: bool,
     pub rank: isize,
     pub enable_rank: bool,
+    pub enable_reverse: bool,
+    pub enable_none: bool,
+    pub enable_total: bool,
+    pub enable_by: bool,
+    pub enable_ptr: bool,
 }
 
 impl FieldAttributeBuilder {
     #[allow(clippy::cognitive_complexity)]
     pub fn from_ord_meta(&self, meta: &Meta) -> FieldAttribute {
         let mut ignore = false;
+        let mut total = false;
+        let mut by = None;
+        let mut ptr = false;
 
         let mut compare_method = None;
         let mut compare_trait = None;
 
         let mut rank = self.rank;
 
+        let mut reverse = false;
+
+        let mut none = FieldAttributeNone::Default;
+
         let correct_usage_for_ord_attribute = {
             let mut usage = vec![];
 
@@ -48,6 +82,27 @@ impl FieldAttributeBuilder {
             usage
         };
 
+        let correct_usage_for_total = {
+            let usage = vec![stringify!(#[educe(Ord(total))])];
+
+            usage
+        };
+
+        let correct_usage_for_ptr = {
+            let usage = vec![stringify!(#[educe(Ord(ptr))])];
+
+            usage
+        };
+
+        let correct_usage_for_by = {
+            let usage = vec![
+                stringify!(#[educe(Ord(by = "expression"))]),
+                stringify!(#[educe(Ord(by("expression")))]),
+            ];
+
+            usage
+        };
+
         let correct_usage_for_impl = {
             let usage = vec![
                 stringify!(#[educe(Ord(method = "path_to_method"))]),
@@ -70,11 +125,31 @@ impl FieldAttributeBuilder {
             usage
         };
 
+        let correct_usage_for_reverse = {
+            let usage = vec![stringify!(#[educe(Ord(reverse))])];
+
+            usage
+        };
+
+        let correct_usage_for_none = {
+            let usage = vec![
+                stringify!(#[educe(Ord(none = "first"))]),
+                stringify!(#[educe(Ord(none = "last"))]),
+                stringify!(#[educe(Ord(none("last")))]),
+            ];
+
+            usage
+        };
+
         let mut rank_is_set = false;
 
         match meta {
             Meta::List(list) => {
                 let mut ignore_is_set = false;
+                let mut total_is_set = false;
+                let mut ptr_is_set = false;
+                let mut reverse_is_set = false;
+                let mut none_is_set = false;
 
                 for p in list.nested.iter() {
                     match p {
@@ -335,6 +410,221 @@ impl FieldAttributeBuilder {
                                         }
                                     }
                                 }
+                                "reverse" => {
+                                    if !self.enable_reverse {
+                                        panic::unknown_parameter("Ord", meta_name.as_str());
+                                    }
+
+                                    match meta {
+                                        Meta::Path(_) => {
+                                            if reverse_is_set {
+                                                panic::reset_parameter(meta_name.as_str());
+                                            }
+
+                                            reverse_is_set = true;
+
+                                            reverse = true;
+                                        }
+                                        _ => {
+                                            panic::parameter_incorrect_format(
+                                                meta_name.as_str(),
+                                                &correct_usage_for_reverse,
+                                            )
+                                        }
+                                    }
+                                }
+                                "none" => {
+                                    if !self.enable_none {
+                                        panic::unknown_parameter("Ord", meta_name.as_str());
+                                    }
+
+                                    match meta {
+                                        Meta::List(list) => {
+                                            for p in list.nested.iter() {
+                                                match p {
+                                                    NestedMeta::Lit(Lit::Str(s)) => {
+                                                        if none_is_set {
+                                                            panic::reset_parameter(
+                                                                meta_name.as_str(),
+                                                            );
+                                                        }
+
+                                                        none_is_set = true;
+
+                                                        none = match FieldAttributeNone::from_str(
+                                                            &s.value(),
+                                                        ) {
+                                                            Some(none) => none,
+                                                            None => {
+                                                                panic::parameter_incorrect_format(
+                                                                    meta_name.as_str(),
+                                                                    &correct_usage_for_none,
+                                                                )
+                                                            }
+                                                        };
+                                                    }
+                                                    _ => {
+                                                        panic::parameter_incorrect_format(
+                                                            meta_name.as_str(),
+                                                            &correct_usage_for_none,
+                                                        )
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        Meta::NameValue(named_value) => {
+                                            let lit = &named_value.lit;
+
+                                            match lit {
+                                                Lit::Str(s) => {
+                                                    if none_is_set {
+                                                        panic::reset_parameter(meta_name.as_str());
+                                                    }
+
+                                                    none_is_set = true;
+
+                                                    none = match FieldAttributeNone::from_str(
+                                                        &s.value(),
+                                                    ) {
+                                                        Some(none) => none,
+                                                        None => {
+                                                            panic::parameter_incorrect_format(
+                                                                meta_name.as_str(),
+                                                                &correct_usage_for_none,
+                                                            )
+                                                        }
+                                                    };
+                                                }
+                                                _ => {
+                                                    panic::parameter_incorrect_format(
+                                                        meta_name.as_str(),
+                                                        &correct_usage_for_none,
+                                                    )
+                                                }
+                                            }
+                                        }
+                                        _ => {
+                                            panic::parameter_incorrect_format(
+                                                meta_name.as_str(),
+                                                &correct_usage_for_none,
+                                            )
+                                        }
+                                    }
+                                }
+                                "total" => {
+                                    if !self.enable_total {
+                                        panic::unknown_parameter("Ord", meta_name.as_str());
+                                    }
+
+                                    match meta {
+                                        Meta::Path(_) => {
+                                            if total_is_set {
+                                                panic::reset_parameter(meta_name.as_str());
+                                            }
+
+                                            total_is_set = true;
+
+                                            total = true;
+                                        }
+                                        _ => {
+                                            panic::parameter_incorrect_format(
+                                                meta_name.as_str(),
+                                                &correct_usage_for_total,
+                                            )
+                                        }
+                                    }
+                                }
+                                "ptr" => {
+                                    if !self.enable_ptr {
+                                        panic::unknown_parameter("Ord", meta_name.as_str());
+                                    }
+
+                                    match meta {
+                                        Meta::Path(_) => {
+                                            if ptr_is_set {
+                                                panic::reset_parameter(meta_name.as_str());
+                                            }
+
+                                            ptr_is_set = true;
+
+                                            ptr = true;
+                                        }
+                                        _ => {
+                                            panic::parameter_incorrect_format(
+                                                meta_name.as_str(),
+                                                &correct_usage_for_ptr,
+                                            )
+                                        }
+                                    }
+                                }
+                                "by" => {
+                                    if !self.enable_by {
+                                        panic::unknown_parameter("Ord", meta_name.as_str());
+                                    }
+
+                                    match meta {
+                                        Meta::List(list) => {
+                                            for p in list.nested.iter() {
+                                                match p {
+                                                    NestedMeta::Lit(Lit::Str(s)) => {
+                                                        if by.is_some() {
+                                                            panic::reset_parameter(
+                                                                meta_name.as_str(),
+                                                            );
+                                                        }
+
+                                                        let s = create_expr_string_from_lit_str(s);
+
+                                                        if let Some(s) = s {
+                                                            by = Some(s);
+                                                        } else {
+                                                            panic::empty_parameter(
+                                                                meta_name.as_str(),
+                                                            );
+                                                        }
+                                                    }
+                                                    _ => {
+                                                        panic::parameter_incorrect_format(
+                                                            meta_name.as_str(),
+                                                            &correct_usage_for_by,
+                                                        )
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        Meta::NameValue(named_value) => {
+                                            let lit = &named_value.lit;
+
+                                            match lit {
+                                                Lit::Str(s) => {
+                                                    if by.is_some() {
+                                                        panic::reset_parameter(meta_name.as_str());
+                                                    }
+
+                                                    let s = create_expr_string_from_lit_str(s);
+
+                                                    if let Some(s) = s {
+                                                        by = Some(s);
+                                                    } else {
+                                                        panic::empty_parameter(meta_name.as_str());
+                                                    }
+                                                }
+                                                _ => {
+                                                    panic::parameter_incorrect_format(
+                                                        meta_name.as_str(),
+                                                        &correct_usage_for_by,
+                                                    )
+                                                }
+                                            }
+                                        }
+                                        _ => {
+                                            panic::parameter_incorrect_format(
+                                                meta_name.as_str(),
+                                                &correct_usage_for_by,
+                                            )
+                                        }
+                                    }
+                                }
                                 _ => panic::unknown_parameter("Ord", meta_name.as_str()),
                             }
                         }
@@ -350,6 +640,29 @@ impl FieldAttributeBuilder {
             _ => panic::attribute_incorrect_format("Ord", &correct_usage_for_ord_attribute),
         }
 
+        if total && (compare_method.is_some() || compare_trait.is_some()) {
+            panic::set_total_impl();
+        }
+
+        if ptr
+            && (total
+                || by.is_some()
+                || compare_method.is_some()
+                || compare_trait.is_some()
+                || none != FieldAttributeNone::Default)
+        {
+            panic::set_ptr_impl();
+        }
+
+        if by.is_some()
+            && (total
+                || compare_method.is_some()
+                || compare_trait.is_some()
+                || none != FieldAttributeNone::Default)
+        {
+            panic::set_by_impl();
+        }
+
         if compare_trait.is_some() && compare_method.is_none() {
             compare_method = Some("cmp".to_string());
         }
@@ -358,11 +671,20 @@ impl FieldAttributeBuilder {
             panic::ignore_ranked_field();
         }
 
+        if ignore && reverse {
+            panic::ignore_reversed_field();
+        }
+
         FieldAttribute {
             ignore,
             compare_method,
             compare_trait,
             rank,
+            reverse,
+            none,
+            total,
+            by,
+            ptr,
         }
     }
 
@@ -411,6 +733,11 @@ impl FieldAttributeBuilder {
             compare_method: None,
             compare_trait: None,
             rank: self.rank,
+            reverse: false,
+            none: FieldAttributeNone::Default,
+            total: false,
+            by: None,
+            ptr: false,
         })
     }
 }
@@ -1,12 +1,13 @@
 use super::super::super::{
-    create_where_predicates_from_generic_parameters, create_where_predicates_from_lit_str,
+    create_expr_from_lit_str, create_where_predicates_from_generic_parameters,
+    create_where_predicates_from_lit_str,
 };
 
 use crate::panic;
 use crate::quote::ToTokens;
 use crate::syn::{
-    punctuated::Punctuated, token::Comma, Attribute, GenericParam, Lit, Meta, NestedMeta,
-    WherePredicate,
+    punctuated::Punctuated, token::Comma, Attribute, GenericParam, Lit, LitStr, Meta,
+    NestedMeta, WherePredicate,
 };
 use crate::Trait;
 
@@ -35,19 +36,43 @@ impl TypeAttributeBound {
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypeAttributeRank {
+    Value(isize),
+    Expression(String),
+    Discriminant,
+}
+
+impl TypeAttributeRank {
+    fn from_lit_str(s: &LitStr) -> Option<TypeAttributeRank> {
+        if s.value().trim() == "discriminant" {
+            return Some(TypeAttributeRank::Discriminant);
+        }
+
+        create_expr_from_lit_str(s)
+            .map(|expr| TypeAttributeRank::Expression(expr.into_token_stream().to_string()))
+    }
+}
+
 #[derive(Clone)]
 pub struct TypeAttribute {
     pub flag: bool,
     pub bound: TypeAttributeBound,
-    pub rank: isize,
+    pub bytes: bool,
+    pub variant_only: bool,
+    pub rank: TypeAttributeRank,
+    pub reverse: bool,
 }
 
 #[derive(Debug, Clone)]
 pub struct TypeAttributeBuilder {
     pub enable_flag: bool,
     pub enable_bound: bool,
+    pub enable_bytes: bool,
+    pub enable_variant_only: bool,
     pub rank: isize,
     pub enable_rank: bool,
+    pub enable_reverse: bool,
 }
 
 impl TypeAttributeBuilder {
@@ -55,7 +80,10 @@ impl TypeAttributeBuilder {
     pub fn from_ord_meta(&self, meta: &Meta) -> TypeAttribute {
         let mut flag = false;
         let mut bound = TypeAttributeBound::None;
-        let mut rank = self.rank;
+        let mut bytes = false;
+        let mut variant_only = false;
+        let mut rank = TypeAttributeRank::Value(self.rank);
+        let mut reverse = false;
 
         let correct_usage_for_ord_attribute = {
             let mut usage = vec![];
@@ -81,15 +109,38 @@ impl TypeAttributeBuilder {
             let usage = vec![
                 stringify!(#[educe(Ord(rank = comparison_value))]),
                 stringify!(#[educe(Ord(rank(comparison_value)))]),
+                stringify!(#[educe(Ord(rank = "const_expression"))]),
+                stringify!(#[educe(Ord(rank = "discriminant"))]),
             ];
 
             usage
         };
 
+        let correct_usage_for_reverse = {
+            let usage = vec![stringify!(#[educe(Ord(reverse))])];
+
+            usage
+        };
+
+        let correct_usage_for_variant_only = {
+            let usage = vec![stringify!(#[educe(Ord(variant_only))])];
+
+            usage
+        };
+
+        let correct_usage_for_bytes = {
+            let usage = vec![stringify!(#[educe(Ord(bytes))])];
+
+            usage
+        };
+
         match meta {
             Meta::List(list) => {
                 let mut bound_is_set = false;
+                let mut bytes_is_set = false;
+                let mut variant_only_is_set = false;
                 let mut rank_is_set = false;
+                let mut reverse_is_set = false;
 
                 for p in list.nested.iter() {
                     match p {
@@ -215,7 +266,30 @@ impl TypeAttributeBuilder {
 
                                                                 rank_is_set = true;
 
-                                                                rank = i.base10_parse().unwrap();
+                                                                rank = TypeAttributeRank::Value(
+                                                                    i.base10_parse().unwrap(),
+                                                                );
+                                                            }
+                                                            Lit::Str(s) => {
+                                                                if rank_is_set {
+                                                                    panic::reset_parameter(
+                                                                        meta_name.as_str(),
+                                                                    );
+                                                                }
+
+                                                                rank_is_set = true;
+
+                                                                let rank_from_lit_str =
+                                                                    TypeAttributeRank::from_lit_str(s);
+
+                                                                rank = match rank_from_lit_str {
+                                                                    Some(rank) => rank,
+                                                                    None => {
+                                                                        panic::empty_parameter(
+                                                                            meta_name.as_str(),
+                                                                        )
+                                                                    }
+                                                                };
                                                             }
                                                             _ => {
                                                                 panic::parameter_incorrect_format(
@@ -245,7 +319,28 @@ impl TypeAttributeBuilder {
 
                                                     rank_is_set = true;
 
-                                                    rank = i.base10_parse().unwrap();
+                                                    rank = TypeAttributeRank::Value(
+                                                        i.base10_parse().unwrap(),
+                                                    );
+                                                }
+                                                Lit::Str(s) => {
+                                                    if rank_is_set {
+                                                        panic::reset_parameter(meta_name.as_str());
+                                                    }
+
+                                                    rank_is_set = true;
+
+                                                    let rank_from_lit_str =
+                                                        TypeAttributeRank::from_lit_str(s);
+
+                                                    rank = match rank_from_lit_str {
+                                                        Some(rank) => rank,
+                                                        None => {
+                                                            panic::empty_parameter(
+                                                                meta_name.as_str(),
+                                                            )
+                                                        }
+                                                    };
                                                 }
                                                 _ => {
                                                     panic::parameter_incorrect_format(
@@ -263,6 +358,75 @@ impl TypeAttributeBuilder {
                                         }
                                     }
                                 }
+                                "reverse" => {
+                                    if !self.enable_reverse {
+                                        panic::unknown_parameter("Ord", meta_name.as_str());
+                                    }
+
+                                    match meta {
+                                        Meta::Path(_) => {
+                                            if reverse_is_set {
+                                                panic::reset_parameter(meta_name.as_str());
+                                            }
+
+                                            reverse_is_set = true;
+
+                                            reverse = true;
+                                        }
+                                        _ => {
+                                            panic::parameter_incorrect_format(
+                                                meta_name.as_str(),
+                                                &correct_usage_for_reverse,
+                                            )
+                                        }
+                                    }
+                                }
+                                "variant_only" => {
+                                    if !self.enable_variant_only {
+                                        panic::unknown_parameter("Ord", meta_name.as_str());
+                                    }
+
+                                    match meta {
+                                        Meta::Path(_) => {
+                                            if variant_only_is_set {
+                                                panic::reset_parameter(meta_name.as_str());
+                                            }
+
+                                            variant_only_is_set = true;
+
+                                            variant_only = true;
+                                        }
+                                        _ => {
+                                            panic::parameter_incorrect_format(
+                                                meta_name.as_str(),
+                                                &correct_usage_for_variant_only,
+                                            )
+                                        }
+                                    }
+                                }
+                                "bytes" => {
+                                    if !self.enable_bytes {
+                                        panic::unknown_parameter("Ord", meta_name.as_str());
+                                    }
+
+                                    match meta {
+                                        Meta::Path(_) => {
+                                            if bytes_is_set {
+                                                panic::reset_parameter(meta_name.as_str());
+                                            }
+
+                                            bytes_is_set = true;
+
+                                            bytes = true;
+                                        }
+                                        _ => {
+                                            panic::parameter_incorrect_format(
+                                                meta_name.as_str(),
+                                                &correct_usage_for_bytes,
+                                            )
+                                        }
+                                    }
+                                }
                                 _ => panic::unknown_parameter("Ord", meta_name.as_str()),
                             }
                         }
@@ -290,7 +454,10 @@ impl TypeAttributeBuilder {
         TypeAttribute {
             flag,
             bound,
+            bytes,
+            variant_only,
             rank,
+            reverse,
         }
     }
 
@@ -337,7 +504,10 @@ impl TypeAttributeBuilder {
         result.unwrap_or(TypeAttribute {
             flag: false,
             bound: TypeAttributeBound::None,
-            rank: self.rank,
+            bytes: false,
+            variant_only: false,
+            rank: TypeAttributeRank::Value(self.rank),
+            reverse: false,
         })
     }
 }
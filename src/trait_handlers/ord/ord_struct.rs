@@ -1,8 +1,11 @@
 use std::collections::BTreeMap;
 use std::str::FromStr;
 
-use super::super::TraitHandler;
-use super::models::{FieldAttributeBuilder, TypeAttributeBuilder};
+use super::super::{
+    create_by_method, create_none_ordering_statement, create_ptr_method,
+    create_total_float_method, TraitHandler,
+};
+use super::models::{FieldAttributeBuilder, FieldAttributeNone, TypeAttributeBuilder};
 
 use crate::panic;
 use crate::proc_macro2::TokenStream;
@@ -21,8 +24,11 @@ impl TraitHandler for OrdStructHandler {
         let type_attribute = TypeAttributeBuilder {
             enable_flag: true,
             enable_bound: true,
+            enable_bytes: false,
+            enable_variant_only: false,
             rank: 0,
             enable_rank: false,
+            enable_reverse: true,
         }
         .from_ord_meta(meta);
 
@@ -37,14 +43,34 @@ impl TraitHandler for OrdStructHandler {
             let mut field_names = BTreeMap::new();
 
             for (index, field) in data.fields.iter().enumerate() {
-                let field_attribute = FieldAttributeBuilder {
+                let mut field_attribute = FieldAttributeBuilder {
                     enable_ignore: true,
                     enable_impl: true,
                     rank: isize::min_value() + index as isize,
                     enable_rank: true,
+                    enable_reverse: true,
+                    enable_none: true,
+                    enable_total: true,
+                    enable_by: true,
+                    enable_ptr: true,
                 }
                 .from_attributes(&field.attrs, traits);
 
+                if field_attribute.total {
+                    field_attribute.compare_method =
+                        Some(create_total_float_method(&field.ty, "cmp"));
+                }
+
+                if let Some(by) = field_attribute.by.as_ref() {
+                    field_attribute.compare_method =
+                        Some(create_by_method(&field.ty, by, "cmp"));
+                }
+
+                if field_attribute.ptr {
+                    field_attribute.compare_method =
+                        Some(create_ptr_method(&field.ty, "cmp"));
+                }
+
                 if field_attribute.ignore {
                     continue;
                 }
@@ -68,32 +94,46 @@ impl TraitHandler for OrdStructHandler {
             for (index, field_attribute) in field_attributes {
                 let field_name = field_names.get(&index).unwrap();
 
+                let none = field_attribute.none;
+
+                let self_value = format!("&self.{}", field_name);
+                let other_value = format!("&other.{}", field_name);
+
+                let (lhs, rhs) = match none {
+                    FieldAttributeNone::Default => (self_value.as_str(), other_value.as_str()),
+                    _ => ("___self", "___other"),
+                };
+
+                let (lhs, rhs) = if type_attribute.reverse ^ field_attribute.reverse {
+                    (rhs, lhs)
+                } else {
+                    (lhs, rhs)
+                };
+
                 let compare_trait = field_attribute.compare_trait;
                 let compare_method = field_attribute.compare_method;
 
-                match compare_trait {
+                let statement = match compare_trait {
                     Some(compare_trait) => {
                         let compare_method = compare_method.unwrap();
 
-                        let statement = format!("match {compare_trait}::{compare_method}(&self.{field_name}, &other.{field_name}) {{ core::cmp::Ordering::Equal => (), core::cmp::Ordering::Greater => {{ return core::cmp::Ordering::Greater; }}, core::cmp::Ordering::Less => {{ return core::cmp::Ordering::Less; }} }}", compare_trait = compare_trait, compare_method = compare_method, field_name = field_name);
-
-                        comparer_tokens.extend(TokenStream::from_str(&statement).unwrap());
+                        format!("match {compare_trait}::{compare_method}({lhs}, {rhs}) {{ core::cmp::Ordering::Equal => (), core::cmp::Ordering::Greater => {{ return core::cmp::Ordering::Greater; }}, core::cmp::Ordering::Less => {{ return core::cmp::Ordering::Less; }} }}", compare_trait = compare_trait, compare_method = compare_method, lhs = lhs, rhs = rhs)
                     }
                     None => {
                         match compare_method {
-                            Some(compare_method) => {
-                                let statement = format!("match {compare_method}(&self.{field_name}, &other.{field_name}) {{ core::cmp::Ordering::Equal => (), core::cmp::Ordering::Greater => {{ return core::cmp::Ordering::Greater; }}, core::cmp::Ordering::Less => {{ return core::cmp::Ordering::Less; }} }}", compare_method = compare_method, field_name = field_name);
-
-                                comparer_tokens.extend(TokenStream::from_str(&statement).unwrap());
-                            }
-                            None => {
-                                let statement = format!("match core::cmp::Ord::cmp(&self.{field_name}, &other.{field_name}) {{ core::cmp::Ordering::Equal => (), core::cmp::Ordering::Greater => {{ return core::cmp::Ordering::Greater; }}, core::cmp::Ordering::Less => {{ return core::cmp::Ordering::Less; }} }}", field_name = field_name);
-
-                                comparer_tokens.extend(TokenStream::from_str(&statement).unwrap());
-                            }
+                            Some(compare_method) => format!("match {compare_method}({lhs}, {rhs}) {{ core::cmp::Ordering::Equal => (), core::cmp::Ordering::Greater => {{ return core::cmp::Ordering::Greater; }}, core::cmp::Ordering::Less => {{ return core::cmp::Ordering::Less; }} }}", compare_method = compare_method, lhs = lhs, rhs = rhs),
+                            None => format!("match core::cmp::Ord::cmp({lhs}, {rhs}) {{ core::cmp::Ordering::Equal => (), core::cmp::Ordering::Greater => {{ return core::cmp::Ordering::Greater; }}, core::cmp::Ordering::Less => {{ return core::cmp::Ordering::Less; }} }}", lhs = lhs, rhs = rhs),
                         }
                     }
-                }
+                };
+
+                let statement = match none {
+                    FieldAttributeNone::Default => statement,
+                    FieldAttributeNone::First => create_none_ordering_statement(&self_value, &other_value, &statement, false, "cmp"),
+                    FieldAttributeNone::Last => create_none_ordering_statement(&self_value, &other_value, &statement, true, "cmp"),
+                };
+
+                comparer_tokens.extend(TokenStream::from_str(&statement).unwrap());
             }
         }
 
@@ -2,16 +2,21 @@ mod models;
 
 mod partial_eq_enum;
 mod partial_eq_struct;
+mod partial_eq_union;
 
 use super::TraitHandler;
 
 use crate::panic;
 use crate::proc_macro2::TokenStream;
-use crate::syn::{Data, DeriveInput, Meta};
+use crate::quote::ToTokens;
+use crate::syn::{Data, DeriveInput, Meta, Type};
 use crate::Trait;
 
 use partial_eq_enum::PartialEqEnumHandler;
 use partial_eq_struct::PartialEqStructHandler;
+use partial_eq_union::PartialEqUnionHandler;
+
+use models::FieldAttributeApprox;
 
 pub struct PartialEqHandler;
 
@@ -29,7 +34,70 @@ impl TraitHandler for PartialEqHandler {
             Data::Enum(_) => {
                 PartialEqEnumHandler::trait_meta_handler(ast, tokens, traits, meta);
             }
-            Data::Union(_) => panic::trait_not_support_union(Trait::PartialEq),
+            Data::Union(_) => {
+                PartialEqUnionHandler::trait_meta_handler(ast, tokens, traits, meta);
+            }
+        }
+    }
+}
+
+fn create_approx_method(ty: &Type, approx: &FieldAttributeApprox, traits: &[Trait]) -> String {
+    if implements_eq_or_hash(traits) {
+        panic::approx_with_eq_or_hash();
+    }
+
+    let body = match approx {
+        FieldAttributeApprox::None => unreachable!(),
+        FieldAttributeApprox::Absolute(tolerance) => {
+            format!(
+                "*a == *b || {{ let d = *a - *b; d <= {tolerance} && -d <= {tolerance} }}",
+                tolerance = tolerance
+            )
+        }
+        FieldAttributeApprox::Relative(tolerance) => {
+            format!("*a == *b || {{ let (a, b) = (*a, *b); let d = if a > b {{ a - b }} else {{ b - a }}; let a = if a < 0.0 {{ -a }} else {{ a }}; let b = if b < 0.0 {{ -b }} else {{ b }}; d <= {tolerance} * (if a > b {{ a }} else {{ b }}) }}", tolerance = tolerance)
+        }
+    };
+
+    let is_sequence = match ty {
+        Type::Array(_) => true,
+        Type::Path(path) => {
+            match path.path.segments.last() {
+                Some(segment) => segment.ident == "Vec",
+                None => false,
+            }
         }
+        _ => false,
+    };
+
+    let ty = ty.into_token_stream().to_string();
+
+    if is_sequence {
+        format!(
+            "(|a: &{ty}, b: &{ty}| a.len() == b.len() && a.iter().zip(b.iter()).all(|(a, b)| {body}))",
+            ty = ty,
+            body = body
+        )
+    } else {
+        format!("(|a: &{ty}, b: &{ty}| {body})", ty = ty, body = body)
     }
 }
+
+#[allow(unused_variables)]
+fn implements_eq_or_hash(traits: &[Trait]) -> bool {
+    #[cfg(feature = "Eq")]
+    {
+        if traits.binary_search(&Trait::Eq).is_ok() {
+            return true;
+        }
+    }
+
+    #[cfg(feature = "Hash")]
+    {
+        if traits.binary_search(&Trait::Hash).is_ok() {
+            return true;
+        }
+    }
+
+    false
+}
@@ -1,27 +1,49 @@
-use super::super::super::create_path_string_from_lit_str;
+use super::super::super::{create_expr_string_from_lit_str, create_path_string_from_lit_str};
 
 use crate::panic;
 use crate::quote::ToTokens;
 use crate::syn::{Attribute, Lit, Meta, NestedMeta};
 use crate::Trait;
 
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldAttributeApprox {
+    None,
+    Absolute(String),
+    Relative(String),
+}
+
 #[derive(Debug, Clone)]
 pub struct FieldAttribute {
     pub ignore: bool,
     pub compare_method: Option<String>,
     pub compare_trait: Option<String>,
+    pub total: bool,
+    pub by: Option<String>,
+    pub ptr: bool,
+    pub unordered: bool,
+    pub approx: FieldAttributeApprox,
 }
 
 #[derive(Debug, Clone)]
 pub struct FieldAttributeBuilder {
     pub enable_ignore: bool,
     pub enable_impl: bool,
+    pub enable_total: bool,
+    pub enable_by: bool,
+    pub enable_ptr: bool,
+    pub enable_unordered: bool,
+    pub enable_approx: bool,
 }
 
 impl FieldAttributeBuilder {
     #[allow(clippy::cognitive_complexity)]
     pub fn from_partial_eq_meta(&self, meta: &Meta) -> FieldAttribute {
         let mut ignore = false;
+        let mut total = false;
+        let mut by = None;
+        let mut ptr = false;
+        let mut unordered = false;
+        let mut approx = FieldAttributeApprox::None;
 
         let mut compare_method = None;
         let mut compare_trait = None;
@@ -43,6 +65,44 @@ impl FieldAttributeBuilder {
             usage
         };
 
+        let correct_usage_for_total = {
+            let usage = vec![stringify!(#[educe(PartialEq(total))])];
+
+            usage
+        };
+
+        let correct_usage_for_ptr = {
+            let usage = vec![stringify!(#[educe(PartialEq(ptr))])];
+
+            usage
+        };
+
+        let correct_usage_for_unordered = {
+            let usage = vec![stringify!(#[educe(PartialEq(unordered))])];
+
+            usage
+        };
+
+        let correct_usage_for_by = {
+            let usage = vec![
+                stringify!(#[educe(PartialEq(by = "expression"))]),
+                stringify!(#[educe(PartialEq(by("expression")))]),
+            ];
+
+            usage
+        };
+
+        let correct_usage_for_approx = {
+            let usage = vec![
+                stringify!(#[educe(PartialEq(approx = tolerance))]),
+                stringify!(#[educe(PartialEq(approx(tolerance)))]),
+                stringify!(#[educe(PartialEq(approx(absolute = tolerance)))]),
+                stringify!(#[educe(PartialEq(approx(relative = tolerance)))]),
+            ];
+
+            usage
+        };
+
         let correct_usage_for_impl = {
             let usage = vec![
                 stringify!(#[educe(PartialEq(method = "path_to_method"))]),
@@ -59,6 +119,10 @@ impl FieldAttributeBuilder {
         match meta {
             Meta::List(list) => {
                 let mut ignore_is_set = false;
+                let mut total_is_set = false;
+                let mut ptr_is_set = false;
+                let mut unordered_is_set = false;
+                let mut approx_is_set = false;
 
                 for p in list.nested.iter() {
                     match p {
@@ -251,6 +315,245 @@ impl FieldAttributeBuilder {
                                         }
                                     }
                                 }
+                                "total" => {
+                                    if !self.enable_total {
+                                        panic::unknown_parameter("PartialEq", meta_name.as_str());
+                                    }
+
+                                    match meta {
+                                        Meta::Path(_) => {
+                                            if total_is_set {
+                                                panic::reset_parameter(meta_name.as_str());
+                                            }
+
+                                            total_is_set = true;
+
+                                            total = true;
+                                        }
+                                        _ => {
+                                            panic::parameter_incorrect_format(
+                                                meta_name.as_str(),
+                                                &correct_usage_for_total,
+                                            )
+                                        }
+                                    }
+                                }
+                                "approx" => {
+                                    if !self.enable_approx {
+                                        panic::unknown_parameter("PartialEq", meta_name.as_str());
+                                    }
+
+                                    match meta {
+                                        Meta::List(list) => {
+                                            for p in list.nested.iter() {
+                                                if approx_is_set {
+                                                    panic::reset_parameter(meta_name.as_str());
+                                                }
+
+                                                approx_is_set = true;
+
+                                                approx = match p {
+                                                    NestedMeta::Lit(lit) => {
+                                                        match create_tolerance_from_lit(lit) {
+                                                            Some(tolerance) => {
+                                                                FieldAttributeApprox::Absolute(
+                                                                    tolerance,
+                                                                )
+                                                            }
+                                                            None => {
+                                                                panic::parameter_incorrect_format(
+                                                                    meta_name.as_str(),
+                                                                    &correct_usage_for_approx,
+                                                                )
+                                                            }
+                                                        }
+                                                    }
+                                                    NestedMeta::Meta(Meta::NameValue(named_value)) => {
+                                                        let tolerance = match create_tolerance_from_lit(&named_value.lit) {
+                                                            Some(tolerance) => tolerance,
+                                                            None => {
+                                                                panic::parameter_incorrect_format(
+                                                                    meta_name.as_str(),
+                                                                    &correct_usage_for_approx,
+                                                                )
+                                                            }
+                                                        };
+
+                                                        let kind = named_value
+                                                            .path
+                                                            .clone()
+                                                            .into_token_stream()
+                                                            .to_string();
+
+                                                        match kind.as_str() {
+                                                            "absolute" => {
+                                                                FieldAttributeApprox::Absolute(
+                                                                    tolerance,
+                                                                )
+                                                            }
+                                                            "relative" => {
+                                                                FieldAttributeApprox::Relative(
+                                                                    tolerance,
+                                                                )
+                                                            }
+                                                            _ => {
+                                                                panic::parameter_incorrect_format(
+                                                                    meta_name.as_str(),
+                                                                    &correct_usage_for_approx,
+                                                                )
+                                                            }
+                                                        }
+                                                    }
+                                                    _ => {
+                                                        panic::parameter_incorrect_format(
+                                                            meta_name.as_str(),
+                                                            &correct_usage_for_approx,
+                                                        )
+                                                    }
+                                                };
+                                            }
+                                        }
+                                        Meta::NameValue(named_value) => {
+                                            if approx_is_set {
+                                                panic::reset_parameter(meta_name.as_str());
+                                            }
+
+                                            approx_is_set = true;
+
+                                            approx = match create_tolerance_from_lit(&named_value.lit) {
+                                                Some(tolerance) => {
+                                                    FieldAttributeApprox::Absolute(tolerance)
+                                                }
+                                                None => {
+                                                    panic::parameter_incorrect_format(
+                                                        meta_name.as_str(),
+                                                        &correct_usage_for_approx,
+                                                    )
+                                                }
+                                            };
+                                        }
+                                        _ => {
+                                            panic::parameter_incorrect_format(
+                                                meta_name.as_str(),
+                                                &correct_usage_for_approx,
+                                            )
+                                        }
+                                    }
+                                }
+                                "ptr" => {
+                                    if !self.enable_ptr {
+                                        panic::unknown_parameter("PartialEq", meta_name.as_str());
+                                    }
+
+                                    match meta {
+                                        Meta::Path(_) => {
+                                            if ptr_is_set {
+                                                panic::reset_parameter(meta_name.as_str());
+                                            }
+
+                                            ptr_is_set = true;
+
+                                            ptr = true;
+                                        }
+                                        _ => {
+                                            panic::parameter_incorrect_format(
+                                                meta_name.as_str(),
+                                                &correct_usage_for_ptr,
+                                            )
+                                        }
+                                    }
+                                }
+                                "unordered" => {
+                                    if !self.enable_unordered {
+                                        panic::unknown_parameter("PartialEq", meta_name.as_str());
+                                    }
+
+                                    match meta {
+                                        Meta::Path(_) => {
+                                            if unordered_is_set {
+                                                panic::reset_parameter(meta_name.as_str());
+                                            }
+
+                                            unordered_is_set = true;
+
+                                            unordered = true;
+                                        }
+                                        _ => {
+                                            panic::parameter_incorrect_format(
+                                                meta_name.as_str(),
+                                                &correct_usage_for_unordered,
+                                            )
+                                        }
+                                    }
+                                }
+                                "by" => {
+                                    if !self.enable_by {
+                                        panic::unknown_parameter("PartialEq", meta_name.as_str());
+                                    }
+
+                                    match meta {
+                                        Meta::List(list) => {
+                                            for p in list.nested.iter() {
+                                                match p {
+                                                    NestedMeta::Lit(Lit::Str(s)) => {
+                                                        if by.is_some() {
+                                                            panic::reset_parameter(
+                                                                meta_name.as_str(),
+                                                            );
+                                                        }
+
+                                                        let s = create_expr_string_from_lit_str(s);
+
+                                                        if let Some(s) = s {
+                                                            by = Some(s);
+                                                        } else {
+                                                            panic::empty_parameter(
+                                                                meta_name.as_str(),
+                                                            );
+                                                        }
+                                                    }
+                                                    _ => {
+                                                        panic::parameter_incorrect_format(
+                                                            meta_name.as_str(),
+                                                            &correct_usage_for_by,
+                                                        )
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        Meta::NameValue(named_value) => {
+                                            let lit = &named_value.lit;
+
+                                            match lit {
+                                                Lit::Str(s) => {
+                                                    if by.is_some() {
+                                                        panic::reset_parameter(meta_name.as_str());
+                                                    }
+
+                                                    let s = create_expr_string_from_lit_str(s);
+
+                                                    if let Some(s) = s {
+                                                        by = Some(s);
+                                                    } else {
+                                                        panic::empty_parameter(meta_name.as_str());
+                                                    }
+                                                }
+                                                _ => {
+                                                    panic::parameter_incorrect_format(
+                                                        meta_name.as_str(),
+                                                        &correct_usage_for_by,
+                                                    )
+                                                }
+                                            }
+                                        }
+                                        _ => {
+                                            panic::parameter_incorrect_format(
+                                                meta_name.as_str(),
+                                                &correct_usage_for_by,
+                                            )
+                                        }
+                                    }
+                                }
                                 _ => panic::unknown_parameter("PartialEq", meta_name.as_str()),
                             }
                         }
@@ -271,6 +574,46 @@ impl FieldAttributeBuilder {
             }
         }
 
+        if total && (compare_method.is_some() || compare_trait.is_some()) {
+            panic::set_total_impl();
+        }
+
+        if unordered
+            && (total
+                || ptr
+                || by.is_some()
+                || compare_method.is_some()
+                || compare_trait.is_some()
+                || approx != FieldAttributeApprox::None)
+        {
+            panic::set_unordered_impl();
+        }
+
+        if ptr
+            && (total
+                || by.is_some()
+                || compare_method.is_some()
+                || compare_trait.is_some()
+                || approx != FieldAttributeApprox::None)
+        {
+            panic::set_ptr_impl();
+        }
+
+        if by.is_some()
+            && (total
+                || compare_method.is_some()
+                || compare_trait.is_some()
+                || approx != FieldAttributeApprox::None)
+        {
+            panic::set_by_impl();
+        }
+
+        if approx != FieldAttributeApprox::None
+            && (total || compare_method.is_some() || compare_trait.is_some())
+        {
+            panic::set_approx_impl();
+        }
+
         if compare_trait.is_some() && compare_method.is_none() {
             compare_method = Some("eq".to_string());
         }
@@ -279,6 +622,11 @@ impl FieldAttributeBuilder {
             ignore,
             compare_method,
             compare_trait,
+            total,
+            by,
+            ptr,
+            unordered,
+            approx,
         }
     }
 
@@ -326,6 +674,19 @@ impl FieldAttributeBuilder {
             ignore: false,
             compare_method: None,
             compare_trait: None,
+            total: false,
+            by: None,
+            ptr: false,
+            unordered: false,
+            approx: FieldAttributeApprox::None,
         })
     }
 }
+
+fn create_tolerance_from_lit(lit: &Lit) -> Option<String> {
+    match lit {
+        Lit::Float(f) => Some(f.base10_digits().to_string()),
+        Lit::Int(i) => Some(format!("{}.0", i.base10_digits())),
+        _ => None,
+    }
+}
@@ -1,12 +1,13 @@
 use super::super::super::{
-    create_where_predicates_from_generic_parameters, create_where_predicates_from_lit_str,
+    create_type_from_lit_str, create_where_predicates_from_generic_parameters,
+    create_where_predicates_from_lit_str,
 };
 
 use crate::panic;
 use crate::quote::ToTokens;
 use crate::syn::{
     punctuated::Punctuated, token::Comma, Attribute, GenericParam, Lit, Meta, NestedMeta,
-    WherePredicate,
+    Type, WherePredicate,
 };
 use crate::Trait;
 
@@ -39,18 +40,27 @@ impl TypeAttributeBound {
 pub struct TypeAttribute {
     pub flag: bool,
     pub bound: TypeAttributeBound,
+    pub bytes: bool,
+    pub variant_only: bool,
+    pub rhs: Vec<Type>,
 }
 
 #[derive(Debug, Clone)]
 pub struct TypeAttributeBuilder {
     pub enable_flag: bool,
     pub enable_bound: bool,
+    pub enable_bytes: bool,
+    pub enable_variant_only: bool,
+    pub enable_rhs: bool,
 }
 
 impl TypeAttributeBuilder {
     pub fn from_partial_eq_meta(&self, meta: &Meta) -> TypeAttribute {
         let mut flag = false;
         let mut bound = TypeAttributeBound::None;
+        let mut bytes = false;
+        let mut variant_only = false;
+        let mut rhs = Vec::new();
 
         let correct_usage_for_partial_eq_attribute = {
             let mut usage = vec![];
@@ -72,9 +82,32 @@ impl TypeAttributeBuilder {
             usage
         };
 
+        let correct_usage_for_rhs = {
+            let usage = vec![
+                stringify!(#[educe(PartialEq(rhs = "Type"))]),
+                stringify!(#[educe(PartialEq(rhs("Type")))]),
+            ];
+
+            usage
+        };
+
+        let correct_usage_for_variant_only = {
+            let usage = vec![stringify!(#[educe(PartialEq(variant_only))])];
+
+            usage
+        };
+
+        let correct_usage_for_bytes = {
+            let usage = vec![stringify!(#[educe(PartialEq(bytes))])];
+
+            usage
+        };
+
         match meta {
             Meta::List(list) => {
                 let mut bound_is_set = false;
+                let mut bytes_is_set = false;
+                let mut variant_only_is_set = false;
 
                 for p in list.nested.iter() {
                     match p {
@@ -180,6 +213,110 @@ impl TypeAttributeBuilder {
                                         }
                                     }
                                 }
+                                "rhs" => {
+                                    if !self.enable_rhs {
+                                        panic::unknown_parameter("PartialEq", meta_name.as_str());
+                                    }
+
+                                    match meta {
+                                        Meta::List(list) => {
+                                            for p in list.nested.iter() {
+                                                match p {
+                                                    NestedMeta::Lit(Lit::Str(s)) => {
+                                                        match create_type_from_lit_str(s) {
+                                                            Some(ty) => rhs.push(ty),
+                                                            None => {
+                                                                panic::empty_parameter(
+                                                                    meta_name.as_str(),
+                                                                )
+                                                            }
+                                                        }
+                                                    }
+                                                    _ => {
+                                                        panic::parameter_incorrect_format(
+                                                            meta_name.as_str(),
+                                                            &correct_usage_for_rhs,
+                                                        )
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        Meta::NameValue(named_value) => {
+                                            let lit = &named_value.lit;
+
+                                            match lit {
+                                                Lit::Str(s) => {
+                                                    match create_type_from_lit_str(s) {
+                                                        Some(ty) => rhs.push(ty),
+                                                        None => {
+                                                            panic::empty_parameter(
+                                                                meta_name.as_str(),
+                                                            )
+                                                        }
+                                                    }
+                                                }
+                                                _ => {
+                                                    panic::parameter_incorrect_format(
+                                                        meta_name.as_str(),
+                                                        &correct_usage_for_rhs,
+                                                    )
+                                                }
+                                            }
+                                        }
+                                        _ => {
+                                            panic::parameter_incorrect_format(
+                                                meta_name.as_str(),
+                                                &correct_usage_for_rhs,
+                                            )
+                                        }
+                                    }
+                                }
+                                "variant_only" => {
+                                    if !self.enable_variant_only {
+                                        panic::unknown_parameter("PartialEq", meta_name.as_str());
+                                    }
+
+                                    match meta {
+                                        Meta::Path(_) => {
+                                            if variant_only_is_set {
+                                                panic::reset_parameter(meta_name.as_str());
+                                            }
+
+                                            variant_only_is_set = true;
+
+                                            variant_only = true;
+                                        }
+                                        _ => {
+                                            panic::parameter_incorrect_format(
+                                                meta_name.as_str(),
+                                                &correct_usage_for_variant_only,
+                                            )
+                                        }
+                                    }
+                                }
+                                "bytes" => {
+                                    if !self.enable_bytes {
+                                        panic::unknown_parameter("PartialEq", meta_name.as_str());
+                                    }
+
+                                    match meta {
+                                        Meta::Path(_) => {
+                                            if bytes_is_set {
+                                                panic::reset_parameter(meta_name.as_str());
+                                            }
+
+                                            bytes_is_set = true;
+
+                                            bytes = true;
+                                        }
+                                        _ => {
+                                            panic::parameter_incorrect_format(
+                                                meta_name.as_str(),
+                                                &correct_usage_for_bytes,
+                                            )
+                                        }
+                                    }
+                                }
                                 _ => panic::unknown_parameter("PartialEq", meta_name.as_str()),
                             }
                         }
@@ -213,6 +350,9 @@ impl TypeAttributeBuilder {
         TypeAttribute {
             flag,
             bound,
+            bytes,
+            variant_only,
+            rhs,
         }
     }
 
@@ -259,6 +399,9 @@ impl TypeAttributeBuilder {
         result.unwrap_or(TypeAttribute {
             flag: false,
             bound: TypeAttributeBound::None,
+            bytes: false,
+            variant_only: false,
+            rhs: Vec::new(),
         })
     }
 }
@@ -1,8 +1,12 @@
 use std::fmt::Write;
 use std::str::FromStr;
 
-use super::super::TraitHandler;
-use super::models::{FieldAttributeBuilder, TypeAttributeBuilder};
+use super::super::{
+    create_by_method, create_ptr_method, create_total_float_method, create_unordered_method,
+    TraitHandler,
+};
+use super::create_approx_method;
+use super::models::{FieldAttributeApprox, FieldAttributeBuilder, TypeAttributeBuilder};
 
 use crate::proc_macro2::TokenStream;
 use crate::syn::{Data, DeriveInput, Fields, Generics, Meta};
@@ -20,6 +24,9 @@ impl TraitHandler for PartialEqEnumHandler {
         let type_attribute = TypeAttributeBuilder {
             enable_flag: true,
             enable_bound: true,
+            enable_bytes: false,
+            enable_variant_only: true,
+            enable_rhs: false,
         }
         .from_partial_eq_meta(meta);
 
@@ -38,6 +45,9 @@ impl TraitHandler for PartialEqEnumHandler {
                 let _ = TypeAttributeBuilder {
                     enable_flag: false,
                     enable_bound: false,
+                    enable_bytes: false,
+                    enable_variant_only: false,
+                    enable_rhs: false,
                 }
                 .from_attributes(&variant.attrs, traits);
 
@@ -58,15 +68,45 @@ impl TraitHandler for PartialEqEnumHandler {
                         let mut field_names = Vec::new();
 
                         for field in fields.named.iter() {
-                            let field_attribute = FieldAttributeBuilder {
+                            let mut field_attribute = FieldAttributeBuilder {
                                 enable_ignore: true,
                                 enable_impl: true,
+                                enable_total: true,
+                                enable_by: true,
+                                enable_ptr: true,
+                                enable_unordered: true,
+                                enable_approx: true,
                             }
                             .from_attributes(&field.attrs, traits);
 
+                            if field_attribute.total {
+                                field_attribute.compare_method =
+                                    Some(create_total_float_method(&field.ty, "eq"));
+                            }
+
+                            if let Some(by) = field_attribute.by.as_ref() {
+                                field_attribute.compare_method =
+                                    Some(create_by_method(&field.ty, by, "eq"));
+                            }
+
+                            if field_attribute.ptr {
+                                field_attribute.compare_method =
+                                    Some(create_ptr_method(&field.ty, "eq"));
+                            }
+
+                            if field_attribute.unordered {
+                                field_attribute.compare_method =
+                                    Some(create_unordered_method(&field.ty, "eq"));
+                            }
+
+                            if field_attribute.approx != FieldAttributeApprox::None {
+                                field_attribute.compare_method =
+                                    Some(create_approx_method(&field.ty, &field_attribute.approx, traits));
+                            }
+
                             let field_name = field.ident.as_ref().unwrap().to_string();
 
-                            if field_attribute.ignore {
+                            if type_attribute.variant_only || field_attribute.ignore {
                                 pattern_tokens
                                     .write_fmt(format_args!(
                                         "{field_name}: _,",
@@ -133,15 +173,45 @@ impl TraitHandler for PartialEqEnumHandler {
                         let mut field_names = Vec::new();
 
                         for (index, field) in fields.unnamed.iter().enumerate() {
-                            let field_attribute = FieldAttributeBuilder {
+                            let mut field_attribute = FieldAttributeBuilder {
                                 enable_ignore: true,
                                 enable_impl: true,
+                                enable_total: true,
+                                enable_by: true,
+                                enable_ptr: true,
+                                enable_unordered: true,
+                                enable_approx: true,
                             }
                             .from_attributes(&field.attrs, traits);
 
+                            if field_attribute.total {
+                                field_attribute.compare_method =
+                                    Some(create_total_float_method(&field.ty, "eq"));
+                            }
+
+                            if let Some(by) = field_attribute.by.as_ref() {
+                                field_attribute.compare_method =
+                                    Some(create_by_method(&field.ty, by, "eq"));
+                            }
+
+                            if field_attribute.ptr {
+                                field_attribute.compare_method =
+                                    Some(create_ptr_method(&field.ty, "eq"));
+                            }
+
+                            if field_attribute.unordered {
+                                field_attribute.compare_method =
+                                    Some(create_unordered_method(&field.ty, "eq"));
+                            }
+
+                            if field_attribute.approx != FieldAttributeApprox::None {
+                                field_attribute.compare_method =
+                                    Some(create_approx_method(&field.ty, &field_attribute.approx, traits));
+                            }
+
                             let field_name = format!("{}", index);
 
-                            if field_attribute.ignore {
+                            if type_attribute.variant_only || field_attribute.ignore {
                                 pattern_tokens.push_str("_,");
                                 pattern_2_tokens.push_str("_,");
                                 continue;
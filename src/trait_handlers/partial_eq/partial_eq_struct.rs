@@ -1,8 +1,13 @@
 use std::str::FromStr;
 
-use super::super::TraitHandler;
-use super::models::{FieldAttributeBuilder, TypeAttributeBuilder};
-
+use super::super::{
+    create_by_method, create_ptr_method, create_rhs_value, create_total_float_method,
+    create_unordered_method, TraitHandler,
+};
+use super::create_approx_method;
+use super::models::{FieldAttributeApprox, FieldAttributeBuilder, TypeAttributeBuilder};
+
+use crate::panic;
 use crate::proc_macro2::TokenStream;
 use crate::syn::{Data, DeriveInput, Generics, Meta};
 use crate::Trait;
@@ -19,6 +24,9 @@ impl TraitHandler for PartialEqStructHandler {
         let type_attribute = TypeAttributeBuilder {
             enable_flag: true,
             enable_bound: true,
+            enable_bytes: false,
+            enable_variant_only: false,
+            enable_rhs: true,
         }
         .from_partial_eq_meta(meta);
 
@@ -27,19 +35,54 @@ impl TraitHandler for PartialEqStructHandler {
             .into_punctuated_where_predicates_by_generic_parameters(&ast.generics.params);
 
         let mut comparer_tokens = TokenStream::new();
+        let mut rhs_comparer_tokens = TokenStream::new();
 
         if let Data::Struct(data) = &ast.data {
             for (index, field) in data.fields.iter().enumerate() {
-                let field_attribute = FieldAttributeBuilder {
+                let mut field_attribute = FieldAttributeBuilder {
                     enable_ignore: true,
                     enable_impl: true,
+                    enable_total: true,
+                    enable_by: true,
+                    enable_ptr: true,
+                    enable_unordered: true,
+                    enable_approx: true,
                 }
                 .from_attributes(&field.attrs, traits);
 
+                if field_attribute.total {
+                    field_attribute.compare_method =
+                        Some(create_total_float_method(&field.ty, "eq"));
+                }
+
+                if let Some(by) = field_attribute.by.as_ref() {
+                    field_attribute.compare_method =
+                        Some(create_by_method(&field.ty, by, "eq"));
+                }
+
+                if field_attribute.ptr {
+                    field_attribute.compare_method =
+                        Some(create_ptr_method(&field.ty, "eq"));
+                }
+
+                if field_attribute.unordered {
+                    field_attribute.compare_method =
+                        Some(create_unordered_method(&field.ty, "eq"));
+                }
+
+                if field_attribute.approx != FieldAttributeApprox::None {
+                    field_attribute.compare_method =
+                        Some(create_approx_method(&field.ty, &field_attribute.approx, traits));
+                }
+
                 if field_attribute.ignore {
                     continue;
                 }
 
+                if !type_attribute.rhs.is_empty() && field_attribute.compare_method.is_some() {
+                    panic::set_rhs_with_field_settings();
+                }
+
                 let compare_trait = field_attribute.compare_trait;
                 let compare_method = field_attribute.compare_method;
 
@@ -49,6 +92,10 @@ impl TraitHandler for PartialEqStructHandler {
                     format!("{}", index)
                 };
 
+                let rhs_statement = format!("if core::cmp::PartialEq::ne(&self.{field_name}, {rhs_value}) {{ return false; }}", field_name = field_name, rhs_value = create_rhs_value(&data.fields, &field_name));
+
+                rhs_comparer_tokens.extend(TokenStream::from_str(&rhs_statement).unwrap());
+
                 match compare_trait {
                     Some(compare_trait) => {
                         let compare_method = compare_method.unwrap();
@@ -99,5 +146,20 @@ impl TraitHandler for PartialEqStructHandler {
         };
 
         tokens.extend(compare_impl);
+
+        for rhs in type_attribute.rhs.iter() {
+            let compare_impl = quote! {
+                impl #impl_generics core::cmp::PartialEq<#rhs> for #ident #ty_generics #where_clause {
+                    #[inline]
+                    fn eq(&self, other: &#rhs) -> bool {
+                        #rhs_comparer_tokens
+
+                        true
+                    }
+                }
+            };
+
+            tokens.extend(compare_impl);
+        }
     }
 }
@@ -0,0 +1,117 @@
+use std::str::FromStr;
+
+use super::super::{create_union_bytes_check, find_union_tag, TraitHandler};
+use super::models::{FieldAttributeBuilder, TypeAttributeBuilder};
+
+use crate::panic;
+use crate::proc_macro2::TokenStream;
+use crate::syn::{Data, DeriveInput, Generics, Meta};
+use crate::Trait;
+
+pub struct PartialEqUnionHandler;
+
+impl TraitHandler for PartialEqUnionHandler {
+    fn trait_meta_handler(
+        ast: &DeriveInput,
+        tokens: &mut TokenStream,
+        traits: &[Trait],
+        meta: &Meta,
+    ) {
+        let type_attribute = TypeAttributeBuilder {
+            enable_flag: true,
+            enable_bound: true,
+            enable_bytes: true,
+            enable_variant_only: false,
+            enable_rhs: false,
+        }
+        .from_partial_eq_meta(meta);
+
+        let union_tag = find_union_tag(ast);
+
+        let bound = type_attribute
+            .bound
+            .into_punctuated_where_predicates_by_generic_parameters(&ast.generics.params);
+
+        if let Data::Union(data) = &ast.data {
+            for field in data.fields.named.iter() {
+                let _ = FieldAttributeBuilder {
+                    enable_ignore: false,
+                    enable_impl: false,
+                    enable_total: false,
+                    enable_by: false,
+                    enable_ptr: false,
+                    enable_unordered: false,
+                    enable_approx: false,
+                }
+                .from_attributes(&field.attrs, traits);
+            }
+        }
+
+        let comparer_tokens = if type_attribute.bytes {
+            if union_tag.is_some() {
+                panic::set_union_tag_bytes();
+            }
+
+            tokens.extend(create_union_bytes_check(ast));
+
+            quote!(
+                let size = core::mem::size_of::<Self>();
+
+                let a = unsafe { core::slice::from_raw_parts(self as *const Self as *const u8, size) };
+                let b = unsafe { core::slice::from_raw_parts(other as *const Self as *const u8, size) };
+
+                a == b
+            )
+        } else {
+            let union_tag = match union_tag {
+                Some(union_tag) => union_tag,
+                None => panic::trait_not_support_union(Trait::PartialEq),
+            };
+
+            let mut match_tokens = String::from("match tag {");
+
+            if let Data::Union(data) = &ast.data {
+                for field in data.fields.named.iter() {
+                    let field_name = field.ident.as_ref().unwrap().to_string();
+
+                    match_tokens.push_str(&format!(
+                        "{field_name:?} => unsafe {{ core::cmp::PartialEq::eq(&self.{field_name}, &other.{field_name}) }},",
+                        field_name = field_name
+                    ));
+                }
+            }
+
+            match_tokens.push_str("_ => core::panic!(\"The union tag returned an unknown field name.\"), }");
+
+            TokenStream::from_str(&format!(
+                "let tag = {union_tag}(self); if tag != {union_tag}(other) {{ return false; }} {match_tokens}",
+                union_tag = union_tag,
+                match_tokens = match_tokens
+            ))
+            .unwrap()
+        };
+
+        let ident = &ast.ident;
+
+        let mut generics_cloned: Generics = ast.generics.clone();
+
+        let where_clause = generics_cloned.make_where_clause();
+
+        for where_predicate in bound {
+            where_clause.predicates.push(where_predicate);
+        }
+
+        let (impl_generics, ty_generics, where_clause) = generics_cloned.split_for_impl();
+
+        let compare_impl = quote! {
+            impl #impl_generics core::cmp::PartialEq for #ident #ty_generics #where_clause {
+                #[inline]
+                fn eq(&self, other: &Self) -> bool {
+                    #comparer_tokens
+                }
+            }
+        };
+
+        tokens.extend(compare_impl);
+    }
+}
@@ -2,16 +2,19 @@ mod models;
 
 mod partial_ord_enum;
 mod partial_ord_struct;
+mod partial_ord_union;
 
 use super::TraitHandler;
+use models::{TypeAttribute, TypeAttributeCrossVariant};
 
 use crate::panic;
 use crate::proc_macro2::TokenStream;
-use crate::syn::{Data, DeriveInput, Meta};
+use crate::syn::{Attribute, Data, DeriveInput, Generics, Meta, NestedMeta};
 use crate::Trait;
 
 use partial_ord_enum::PartialOrdEnumHandler;
 use partial_ord_struct::PartialOrdStructHandler;
+use partial_ord_union::PartialOrdUnionHandler;
 
 pub struct PartialOrdHandler;
 
@@ -29,7 +32,108 @@ impl TraitHandler for PartialOrdHandler {
             Data::Enum(_) => {
                 PartialOrdEnumHandler::trait_meta_handler(ast, tokens, traits, meta);
             }
-            Data::Union(_) => panic::trait_not_support_union(Trait::PartialOrd),
+            Data::Union(_) => {
+                PartialOrdUnionHandler::trait_meta_handler(ast, tokens, traits, meta);
+            }
+        }
+    }
+}
+
+fn create_via_ord_impl(
+    ast: &DeriveInput,
+    tokens: &mut TokenStream,
+    type_attribute: TypeAttribute,
+) {
+    if type_attribute.bytes
+        || type_attribute.variant_only
+        || type_attribute.reverse
+        || !type_attribute.rhs.is_empty()
+        || type_attribute.cross_variant != TypeAttributeCrossVariant::Default
+    {
+        panic::set_via_ord_with_settings();
+    }
+
+    let has_settings = match &ast.data {
+        Data::Struct(data) => {
+            data.fields.iter().any(|field| has_partial_ord_attribute(&field.attrs))
+        }
+        Data::Enum(data) => {
+            data.variants.iter().any(|variant| {
+                has_partial_ord_attribute(&variant.attrs)
+                    || variant.fields.iter().any(|field| has_partial_ord_attribute(&field.attrs))
+            })
+        }
+        Data::Union(data) => {
+            data.fields.named.iter().any(|field| has_partial_ord_attribute(&field.attrs))
+        }
+    };
+
+    if has_settings {
+        panic::set_via_ord_with_settings();
+    }
+
+    let bound = type_attribute
+        .bound
+        .into_punctuated_where_predicates_by_generic_parameters(&ast.generics.params);
+
+    let ident = &ast.ident;
+
+    let mut generics_cloned: Generics = ast.generics.clone();
+
+    let (_, ty_generics, _) = ast.generics.split_for_impl();
+
+    let where_clause = generics_cloned.make_where_clause();
+
+    for where_predicate in bound {
+        where_clause.predicates.push(where_predicate);
+    }
+
+    where_clause
+        .predicates
+        .push(syn::parse2(quote!(#ident #ty_generics: core::cmp::Ord)).unwrap());
+
+    let (impl_generics, ty_generics, where_clause) = generics_cloned.split_for_impl();
+
+    let compare_impl = quote! {
+        impl #impl_generics core::cmp::PartialOrd for #ident #ty_generics #where_clause {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                Some(core::cmp::Ord::cmp(self, other))
+            }
+        }
+    };
+
+    tokens.extend(compare_impl);
+}
+
+fn has_partial_ord_attribute(attributes: &[Attribute]) -> bool {
+    attributes.iter().any(|attribute| {
+        if !attribute.path.is_ident("educe") {
+            return false;
+        }
+
+        match attribute.parse_meta() {
+            Ok(Meta::List(list)) => {
+                list.nested.iter().any(|p| {
+                    match p {
+                        NestedMeta::Meta(meta) => meta.path().is_ident("PartialOrd"),
+                        _ => false,
+                    }
+                })
+            }
+            _ => false,
+        }
+    })
+}
+
+#[allow(unused_variables)]
+fn implements_ord(traits: &[Trait]) -> bool {
+    #[cfg(feature = "Ord")]
+    {
+        if traits.binary_search(&Trait::Ord).is_ok() {
+            return true;
         }
     }
+
+    false
 }
@@ -1,12 +1,13 @@
 use super::super::super::{
+    create_expr_from_lit_str, create_type_from_lit_str,
     create_where_predicates_from_generic_parameters, create_where_predicates_from_lit_str,
 };
 
 use crate::panic;
 use crate::quote::ToTokens;
 use crate::syn::{
-    punctuated::Punctuated, token::Comma, Attribute, GenericParam, Lit, Meta, NestedMeta,
-    WherePredicate,
+    punctuated::Punctuated, token::Comma, Attribute, GenericParam, Lit, LitStr, Meta,
+    NestedMeta, Type, WherePredicate,
 };
 use crate::Trait;
 
@@ -35,19 +36,66 @@ impl TypeAttributeBound {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TypeAttributeCrossVariant {
+    Default,
+    Rank,
+    None,
+}
+
+impl TypeAttributeCrossVariant {
+    pub fn from_str(s: &str) -> Option<TypeAttributeCrossVariant> {
+        match s {
+            "rank" => Some(TypeAttributeCrossVariant::Rank),
+            "none" => Some(TypeAttributeCrossVariant::None),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypeAttributeRank {
+    Value(isize),
+    Expression(String),
+    Discriminant,
+}
+
+impl TypeAttributeRank {
+    fn from_lit_str(s: &LitStr) -> Option<TypeAttributeRank> {
+        if s.value().trim() == "discriminant" {
+            return Some(TypeAttributeRank::Discriminant);
+        }
+
+        create_expr_from_lit_str(s)
+            .map(|expr| TypeAttributeRank::Expression(expr.into_token_stream().to_string()))
+    }
+}
+
 #[derive(Clone)]
 pub struct TypeAttribute {
     pub flag: bool,
     pub bound: TypeAttributeBound,
-    pub rank: isize,
+    pub bytes: bool,
+    pub variant_only: bool,
+    pub rhs: Vec<Type>,
+    pub via_ord: bool,
+    pub cross_variant: TypeAttributeCrossVariant,
+    pub rank: TypeAttributeRank,
+    pub reverse: bool,
 }
 
 #[derive(Debug, Clone)]
 pub struct TypeAttributeBuilder {
     pub enable_flag: bool,
     pub enable_bound: bool,
+    pub enable_bytes: bool,
+    pub enable_variant_only: bool,
+    pub enable_rhs: bool,
+    pub enable_via_ord: bool,
+    pub enable_cross_variant: bool,
     pub rank: isize,
     pub enable_rank: bool,
+    pub enable_reverse: bool,
 }
 
 impl TypeAttributeBuilder {
@@ -55,7 +103,13 @@ impl TypeAttributeBuilder {
     pub fn from_partial_ord_meta(&self, meta: &Meta) -> TypeAttribute {
         let mut flag = false;
         let mut bound = TypeAttributeBound::None;
-        let mut rank = self.rank;
+        let mut bytes = false;
+        let mut variant_only = false;
+        let mut rhs = Vec::new();
+        let mut via_ord = false;
+        let mut cross_variant = TypeAttributeCrossVariant::Default;
+        let mut rank = TypeAttributeRank::Value(self.rank);
+        let mut reverse = false;
 
         let correct_usage_for_partial_ord_attribute = {
             let mut usage = vec![];
@@ -81,15 +135,65 @@ impl TypeAttributeBuilder {
             let usage = vec![
                 stringify!(#[educe(PartialOrd(rank = comparison_value))]),
                 stringify!(#[educe(PartialOrd(rank(comparison_value)))]),
+                stringify!(#[educe(PartialOrd(rank = "const_expression"))]),
+                stringify!(#[educe(PartialOrd(rank = "discriminant"))]),
+            ];
+
+            usage
+        };
+
+        let correct_usage_for_reverse = {
+            let usage = vec![stringify!(#[educe(PartialOrd(reverse))])];
+
+            usage
+        };
+
+        let correct_usage_for_rhs = {
+            let usage = vec![
+                stringify!(#[educe(PartialOrd(rhs = "Type"))]),
+                stringify!(#[educe(PartialOrd(rhs("Type")))]),
+            ];
+
+            usage
+        };
+
+        let correct_usage_for_cross_variant = {
+            let usage = vec![
+                stringify!(#[educe(PartialOrd(cross_variant = "none"))]),
+                stringify!(#[educe(PartialOrd(cross_variant = "rank"))]),
+                stringify!(#[educe(PartialOrd(cross_variant("none")))]),
             ];
 
             usage
         };
 
+        let correct_usage_for_via_ord = {
+            let usage = vec![stringify!(#[educe(PartialOrd(via_ord))])];
+
+            usage
+        };
+
+        let correct_usage_for_variant_only = {
+            let usage = vec![stringify!(#[educe(PartialOrd(variant_only))])];
+
+            usage
+        };
+
+        let correct_usage_for_bytes = {
+            let usage = vec![stringify!(#[educe(PartialOrd(bytes))])];
+
+            usage
+        };
+
         match meta {
             Meta::List(list) => {
                 let mut bound_is_set = false;
+                let mut bytes_is_set = false;
+                let mut variant_only_is_set = false;
+                let mut via_ord_is_set = false;
+                let mut cross_variant_is_set = false;
                 let mut rank_is_set = false;
+                let mut reverse_is_set = false;
 
                 for p in list.nested.iter() {
                     match p {
@@ -215,7 +319,30 @@ impl TypeAttributeBuilder {
 
                                                                 rank_is_set = true;
 
-                                                                rank = i.base10_parse().unwrap();
+                                                                rank = TypeAttributeRank::Value(
+                                                                    i.base10_parse().unwrap(),
+                                                                );
+                                                            }
+                                                            Lit::Str(s) => {
+                                                                if rank_is_set {
+                                                                    panic::reset_parameter(
+                                                                        meta_name.as_str(),
+                                                                    );
+                                                                }
+
+                                                                rank_is_set = true;
+
+                                                                let rank_from_lit_str =
+                                                                    TypeAttributeRank::from_lit_str(s);
+
+                                                                rank = match rank_from_lit_str {
+                                                                    Some(rank) => rank,
+                                                                    None => {
+                                                                        panic::empty_parameter(
+                                                                            meta_name.as_str(),
+                                                                        )
+                                                                    }
+                                                                };
                                                             }
                                                             _ => {
                                                                 panic::parameter_incorrect_format(
@@ -245,7 +372,28 @@ impl TypeAttributeBuilder {
 
                                                     rank_is_set = true;
 
-                                                    rank = i.base10_parse().unwrap();
+                                                    rank = TypeAttributeRank::Value(
+                                                        i.base10_parse().unwrap(),
+                                                    );
+                                                }
+                                                Lit::Str(s) => {
+                                                    if rank_is_set {
+                                                        panic::reset_parameter(meta_name.as_str());
+                                                    }
+
+                                                    rank_is_set = true;
+
+                                                    let rank_from_lit_str =
+                                                        TypeAttributeRank::from_lit_str(s);
+
+                                                    rank = match rank_from_lit_str {
+                                                        Some(rank) => rank,
+                                                        None => {
+                                                            panic::empty_parameter(
+                                                                meta_name.as_str(),
+                                                            )
+                                                        }
+                                                    };
                                                 }
                                                 _ => {
                                                     panic::parameter_incorrect_format(
@@ -263,6 +411,236 @@ impl TypeAttributeBuilder {
                                         }
                                     }
                                 }
+                                "reverse" => {
+                                    if !self.enable_reverse {
+                                        panic::unknown_parameter("PartialOrd", meta_name.as_str());
+                                    }
+
+                                    match meta {
+                                        Meta::Path(_) => {
+                                            if reverse_is_set {
+                                                panic::reset_parameter(meta_name.as_str());
+                                            }
+
+                                            reverse_is_set = true;
+
+                                            reverse = true;
+                                        }
+                                        _ => {
+                                            panic::parameter_incorrect_format(
+                                                meta_name.as_str(),
+                                                &correct_usage_for_reverse,
+                                            )
+                                        }
+                                    }
+                                }
+                                "rhs" => {
+                                    if !self.enable_rhs {
+                                        panic::unknown_parameter("PartialOrd", meta_name.as_str());
+                                    }
+
+                                    match meta {
+                                        Meta::List(list) => {
+                                            for p in list.nested.iter() {
+                                                match p {
+                                                    NestedMeta::Lit(Lit::Str(s)) => {
+                                                        match create_type_from_lit_str(s) {
+                                                            Some(ty) => rhs.push(ty),
+                                                            None => {
+                                                                panic::empty_parameter(
+                                                                    meta_name.as_str(),
+                                                                )
+                                                            }
+                                                        }
+                                                    }
+                                                    _ => {
+                                                        panic::parameter_incorrect_format(
+                                                            meta_name.as_str(),
+                                                            &correct_usage_for_rhs,
+                                                        )
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        Meta::NameValue(named_value) => {
+                                            let lit = &named_value.lit;
+
+                                            match lit {
+                                                Lit::Str(s) => {
+                                                    match create_type_from_lit_str(s) {
+                                                        Some(ty) => rhs.push(ty),
+                                                        None => {
+                                                            panic::empty_parameter(
+                                                                meta_name.as_str(),
+                                                            )
+                                                        }
+                                                    }
+                                                }
+                                                _ => {
+                                                    panic::parameter_incorrect_format(
+                                                        meta_name.as_str(),
+                                                        &correct_usage_for_rhs,
+                                                    )
+                                                }
+                                            }
+                                        }
+                                        _ => {
+                                            panic::parameter_incorrect_format(
+                                                meta_name.as_str(),
+                                                &correct_usage_for_rhs,
+                                            )
+                                        }
+                                    }
+                                }
+                                "cross_variant" => {
+                                    if !self.enable_cross_variant {
+                                        panic::unknown_parameter("PartialOrd", meta_name.as_str());
+                                    }
+
+                                    match meta {
+                                        Meta::List(list) => {
+                                            for p in list.nested.iter() {
+                                                match p {
+                                                    NestedMeta::Lit(Lit::Str(s)) => {
+                                                        if cross_variant_is_set {
+                                                            panic::reset_parameter(
+                                                                meta_name.as_str(),
+                                                            );
+                                                        }
+
+                                                        cross_variant_is_set = true;
+
+                                                        cross_variant =
+                                                            match TypeAttributeCrossVariant::from_str(
+                                                                &s.value(),
+                                                            ) {
+                                                                Some(cross_variant) => cross_variant,
+                                                                None => {
+                                                                    panic::parameter_incorrect_format(
+                                                                        meta_name.as_str(),
+                                                                        &correct_usage_for_cross_variant,
+                                                                    )
+                                                                }
+                                                            };
+                                                    }
+                                                    _ => {
+                                                        panic::parameter_incorrect_format(
+                                                            meta_name.as_str(),
+                                                            &correct_usage_for_cross_variant,
+                                                        )
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        Meta::NameValue(named_value) => {
+                                            let lit = &named_value.lit;
+
+                                            match lit {
+                                                Lit::Str(s) => {
+                                                    if cross_variant_is_set {
+                                                        panic::reset_parameter(meta_name.as_str());
+                                                    }
+
+                                                    cross_variant_is_set = true;
+
+                                                    cross_variant =
+                                                        match TypeAttributeCrossVariant::from_str(
+                                                            &s.value(),
+                                                        ) {
+                                                            Some(cross_variant) => cross_variant,
+                                                            None => {
+                                                                panic::parameter_incorrect_format(
+                                                                    meta_name.as_str(),
+                                                                    &correct_usage_for_cross_variant,
+                                                                )
+                                                            }
+                                                        };
+                                                }
+                                                _ => {
+                                                    panic::parameter_incorrect_format(
+                                                        meta_name.as_str(),
+                                                        &correct_usage_for_cross_variant,
+                                                    )
+                                                }
+                                            }
+                                        }
+                                        _ => {
+                                            panic::parameter_incorrect_format(
+                                                meta_name.as_str(),
+                                                &correct_usage_for_cross_variant,
+                                            )
+                                        }
+                                    }
+                                }
+                                "via_ord" => {
+                                    if !self.enable_via_ord {
+                                        panic::unknown_parameter("PartialOrd", meta_name.as_str());
+                                    }
+
+                                    match meta {
+                                        Meta::Path(_) => {
+                                            if via_ord_is_set {
+                                                panic::reset_parameter(meta_name.as_str());
+                                            }
+
+                                            via_ord_is_set = true;
+
+                                            via_ord = true;
+                                        }
+                                        _ => {
+                                            panic::parameter_incorrect_format(
+                                                meta_name.as_str(),
+                                                &correct_usage_for_via_ord,
+                                            )
+                                        }
+                                    }
+                                }
+                                "variant_only" => {
+                                    if !self.enable_variant_only {
+                                        panic::unknown_parameter("PartialOrd", meta_name.as_str());
+                                    }
+
+                                    match meta {
+                                        Meta::Path(_) => {
+                                            if variant_only_is_set {
+                                                panic::reset_parameter(meta_name.as_str());
+                                            }
+
+                                            variant_only_is_set = true;
+
+                                            variant_only = true;
+                                        }
+                                        _ => {
+                                            panic::parameter_incorrect_format(
+                                                meta_name.as_str(),
+                                                &correct_usage_for_variant_only,
+                                            )
+                                        }
+                                    }
+                                }
+                                "bytes" => {
+                                    if !self.enable_bytes {
+                                        panic::unknown_parameter("PartialOrd", meta_name.as_str());
+                                    }
+
+                                    match meta {
+                                        Meta::Path(_) => {
+                                            if bytes_is_set {
+                                                panic::reset_parameter(meta_name.as_str());
+                                            }
+
+                                            bytes_is_set = true;
+
+                                            bytes = true;
+                                        }
+                                        _ => {
+                                            panic::parameter_incorrect_format(
+                                                meta_name.as_str(),
+                                                &correct_usage_for_bytes,
+                                            )
+                                        }
+                                    }
+                                }
                                 _ => panic::unknown_parameter("PartialOrd", meta_name.as_str()),
                             }
                         }
@@ -296,7 +674,13 @@ impl TypeAttributeBuilder {
         TypeAttribute {
             flag,
             bound,
+            bytes,
+            variant_only,
+            rhs,
+            via_ord,
+            cross_variant,
             rank,
+            reverse,
         }
     }
 
@@ -343,7 +727,13 @@ impl TypeAttributeBuilder {
         result.unwrap_or(TypeAttribute {
             flag: false,
             bound: TypeAttributeBound::None,
-            rank: self.rank,
+            bytes: false,
+            variant_only: false,
+            rhs: Vec::new(),
+            via_ord: false,
+            cross_variant: TypeAttributeCrossVariant::Default,
+            rank: TypeAttributeRank::Value(self.rank),
+            reverse: false,
         })
     }
 }
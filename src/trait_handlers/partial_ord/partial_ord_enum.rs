@@ -2,11 +2,19 @@ use std::collections::BTreeMap;
 use std::fmt::Write;
 use std::str::FromStr;
 
-use super::super::TraitHandler;
-use super::models::{FieldAttributeBuilder, TypeAttributeBuilder};
+use super::super::{
+    create_by_method, create_none_ordering_statement, create_ptr_method,
+    create_rank_uniqueness_check, create_total_float_method, TraitHandler,
+};
+use super::{create_via_ord_impl, implements_ord};
+use super::models::{
+    FieldAttributeBuilder, FieldAttributeNone, TypeAttributeBuilder, TypeAttributeCrossVariant,
+    TypeAttributeRank,
+};
 
 use crate::panic;
 use crate::proc_macro2::TokenStream;
+use crate::quote::ToTokens;
 use crate::syn::{Data, DeriveInput, Fields, Generics, Meta};
 use crate::Trait;
 
@@ -22,11 +30,21 @@ impl TraitHandler for PartialOrdEnumHandler {
         let type_attribute = TypeAttributeBuilder {
             enable_flag: true,
             enable_bound: true,
+            enable_bytes: false,
+            enable_variant_only: true,
+            enable_rhs: false,
+            enable_via_ord: true,
+            enable_cross_variant: true,
             rank: 0,
             enable_rank: false,
+            enable_reverse: true,
         }
         .from_partial_ord_meta(meta);
 
+        if type_attribute.via_ord {
+            return create_via_ord_impl(ast, tokens, type_attribute);
+        }
+
         let enum_name = ast.ident.to_string();
 
         let bound = type_attribute
@@ -41,6 +59,9 @@ impl TraitHandler for PartialOrdEnumHandler {
 
         if let Data::Enum(data) = &ast.data {
             let mut variant_values = Vec::new();
+            let mut variant_rank_values = Vec::new();
+            let mut variant_reverses = Vec::new();
+            let mut variant_incomparables = Vec::new();
             let mut variant_idents = Vec::new();
             let mut variants = Vec::new();
 
@@ -48,27 +69,94 @@ impl TraitHandler for PartialOrdEnumHandler {
                 String::from("let variant_to_integer = |other: &Self| match other {");
             let mut unit_to_integer =
                 String::from("let unit_to_integer = |other: &Self| match other {");
+            let mut variant_is_comparable =
+                String::from("let variant_is_comparable = |other: &Self| match other {");
+
+            let mut has_incomparable = false;
+
+            let mut has_rank_expression = false;
+
+            let unit_only =
+                data.variants.iter().all(|variant| matches!(variant.fields, Fields::Unit));
 
             for (index, variant) in data.variants.iter().enumerate() {
                 let variant_attribute = TypeAttributeBuilder {
                     enable_flag: false,
                     enable_bound: false,
+                    enable_bytes: false,
+                    enable_variant_only: false,
+                    enable_rhs: false,
+                    enable_via_ord: false,
+                    enable_cross_variant: true,
                     rank: isize::min_value() + index as isize,
                     enable_rank: true,
+                    enable_reverse: true,
                 }
                 .from_attributes(&variant.attrs, traits);
 
-                let value = variant_attribute.rank;
+                let value = match variant_attribute.rank {
+                    TypeAttributeRank::Value(value) => {
+                        if variant_rank_values.contains(&value) {
+                            panic::reuse_a_value(value);
+                        }
 
-                if variant_values.contains(&value) {
-                    panic::reuse_a_value(value);
-                }
+                        if value >= 0 {
+                            has_non_unit_or_custom_value = true;
+                        }
+
+                        variant_rank_values.push(value);
+
+                        format!("{}isize", value)
+                    }
+                    TypeAttributeRank::Expression(expression) => {
+                        has_non_unit_or_custom_value = true;
+                        has_rank_expression = true;
+
+                        format!("(({}) as isize)", expression)
+                    }
+                    TypeAttributeRank::Discriminant => {
+                        has_non_unit_or_custom_value = true;
+                        has_rank_expression = true;
+
+                        match variant.discriminant.as_ref() {
+                            Some((_, expression)) => {
+                                format!("(({}) as isize)", expression.into_token_stream())
+                            }
+                            None if unit_only => {
+                                format!("({}::{} as isize)", enum_name, variant.ident)
+                            }
+                            None => panic::rank_without_discriminant(&variant.ident.to_string()),
+                        }
+                    }
+                };
+
+                let variant_ident = variant.ident.to_string();
+
+                let incomparable = match variant_attribute.cross_variant {
+                    TypeAttributeCrossVariant::Default => {
+                        type_attribute.cross_variant == TypeAttributeCrossVariant::None
+                    }
+                    cross_variant => cross_variant == TypeAttributeCrossVariant::None,
+                };
 
-                if value >= 0 {
+                if incomparable {
+                    if implements_ord(traits) {
+                        panic::cross_variant_none_with_ord();
+                    }
+
+                    // Unit variants can no longer be compared by their discriminants alone.
+                    has_incomparable = true;
                     has_non_unit_or_custom_value = true;
                 }
 
-                let variant_ident = variant.ident.to_string();
+                variant_is_comparable
+                    .write_fmt(format_args!(
+                        "{enum_name}::{variant_ident} {{ .. }} => {comparable},",
+                        enum_name = enum_name,
+                        variant_ident = variant_ident,
+                        comparable = !incomparable
+                    ))
+                    .unwrap();
 
                 match &variant.fields {
                     Fields::Unit => {
@@ -119,6 +207,8 @@ impl TraitHandler for PartialOrdEnumHandler {
                 }
 
                 variant_values.push(value);
+                variant_reverses.push(type_attribute.reverse ^ variant_attribute.reverse);
+                variant_incomparables.push(incomparable);
                 variant_idents.push(variant_ident);
                 variants.push(variant);
             }
@@ -126,16 +216,50 @@ impl TraitHandler for PartialOrdEnumHandler {
             if has_non_unit_or_custom_value {
                 variant_to_integer.push_str("};");
 
+                if has_rank_expression {
+                    comparer_tokens.extend(
+                        TokenStream::from_str(&create_rank_uniqueness_check(&variant_values))
+                            .unwrap(),
+                    );
+                }
+
                 comparer_tokens.extend(TokenStream::from_str(&variant_to_integer).unwrap());
 
+                if has_incomparable {
+                    variant_is_comparable.push_str("};");
+
+                    comparer_tokens
+                        .extend(TokenStream::from_str(&variant_is_comparable).unwrap());
+                }
+
                 for (index, variant) in variants.into_iter().enumerate() {
-                    let variant_value = variant_values[index];
+                    let variant_value = &variant_values[index];
+                    let variant_reverse = variant_reverses[index];
+                    let variant_incomparable = variant_incomparables[index];
                     let variant_ident = &variant_idents[index];
 
+                    let variant_order = if type_attribute.reverse {
+                        format!("&other_value, &{}", variant_value)
+                    } else {
+                        format!("&{}, &other_value", variant_value)
+                    };
+
+                    let cross_variant_statement = if variant_incomparable {
+                        String::from("return None;")
+                    } else if has_incomparable {
+                        format!("if !variant_is_comparable(other) {{ return None; }} let other_value = variant_to_integer(other); return core::cmp::PartialOrd::partial_cmp({variant_order});", variant_order = variant_order)
+                    } else {
+                        format!("let other_value = variant_to_integer(other); return core::cmp::PartialOrd::partial_cmp({variant_order});", variant_order = variant_order)
+                    };
+
                     match &variant.fields {
                         Fields::Unit => {
                             // TODO Unit
-                            match_tokens.write_fmt(format_args!("{enum_name}::{variant_ident} => {{ let other_value = variant_to_integer(other); return core::cmp::PartialOrd::partial_cmp(&{variant_value}, &other_value); }}", enum_name = enum_name, variant_ident = variant_ident, variant_value = variant_value)).unwrap();
+                            if variant_incomparable {
+                                match_tokens.write_fmt(format_args!("{enum_name}::{variant_ident} => {{ if let {enum_name}::{variant_ident} = other {{ return Some(core::cmp::Ordering::Equal); }} {cross_variant_statement} }}", enum_name = enum_name, variant_ident = variant_ident, cross_variant_statement = cross_variant_statement)).unwrap();
+                            } else {
+                                match_tokens.write_fmt(format_args!("{enum_name}::{variant_ident} => {{ {cross_variant_statement} }}", enum_name = enum_name, variant_ident = variant_ident, cross_variant_statement = cross_variant_statement)).unwrap();
+                            }
                         }
                         Fields::Named(fields) => {
                             // TODO Struct
@@ -147,17 +271,37 @@ impl TraitHandler for PartialOrdEnumHandler {
                             let mut field_names = BTreeMap::new();
 
                             for (index, field) in fields.named.iter().enumerate() {
-                                let field_attribute = FieldAttributeBuilder {
+                                let mut field_attribute = FieldAttributeBuilder {
                                     enable_ignore: true,
                                     enable_impl: true,
                                     rank: isize::min_value() + index as isize,
                                     enable_rank: true,
+                                    enable_reverse: true,
+                                    enable_none: true,
+                                    enable_total: true,
+                                    enable_by: true,
+                                    enable_ptr: true,
                                 }
                                 .from_attributes(&field.attrs, traits);
 
+                                if field_attribute.total {
+                                    field_attribute.compare_method =
+                                        Some(create_total_float_method(&field.ty, "partial_cmp"));
+                                }
+
+                                if let Some(by) = field_attribute.by.as_ref() {
+                                    field_attribute.compare_method =
+                                        Some(create_by_method(&field.ty, by, "partial_cmp"));
+                                }
+
+                                if field_attribute.ptr {
+                                    field_attribute.compare_method =
+                                        Some(create_ptr_method(&field.ty, "partial_cmp"));
+                                }
+
                                 let field_name = field.ident.as_ref().unwrap().to_string();
 
-                                if field_attribute.ignore {
+                                if type_attribute.variant_only || field_attribute.ignore {
                                     pattern_tokens
                                         .write_fmt(format_args!(
                                             "{field_name}: _,",
@@ -199,29 +343,51 @@ impl TraitHandler for PartialOrdEnumHandler {
                             for (index, field_attribute) in field_attributes {
                                 let field_name = field_names.get(&index).unwrap();
 
+                                let none = field_attribute.none;
+
+                                let self_value = field_name.clone();
+                                let other_value = format!("___{}", field_name);
+
+                                let (lhs, rhs) = match none {
+                                    FieldAttributeNone::Default => {
+                                        (self_value.as_str(), other_value.as_str())
+                                    }
+                                    _ => ("___self", "___other"),
+                                };
+
+                                let (lhs, rhs) = if variant_reverse ^ field_attribute.reverse {
+                                    (rhs, lhs)
+                                } else {
+                                    (lhs, rhs)
+                                };
+
                                 let compare_trait = field_attribute.compare_trait;
                                 let compare_method = field_attribute.compare_method;
 
-                                match compare_trait {
+                                let statement = match compare_trait {
                                     Some(compare_trait) => {
                                         let compare_method = compare_method.unwrap();
 
-                                        block_tokens.write_fmt(format_args!("match {compare_trait}::{compare_method}({field_name}, ___{field_name}) {{ Some(core::cmp::Ordering::Equal) => (), Some(core::cmp::Ordering::Greater) => {{ return Some(core::cmp::Ordering::Greater); }}, Some(core::cmp::Ordering::Less) => {{ return Some(core::cmp::Ordering::Less); }}, None => {{ return None; }} }}", compare_trait = compare_trait, compare_method = compare_method, field_name = field_name)).unwrap();
+                                        format!("match {compare_trait}::{compare_method}({lhs}, {rhs}) {{ Some(core::cmp::Ordering::Equal) => (), Some(core::cmp::Ordering::Greater) => {{ return Some(core::cmp::Ordering::Greater); }}, Some(core::cmp::Ordering::Less) => {{ return Some(core::cmp::Ordering::Less); }}, None => {{ return None; }} }}", compare_trait = compare_trait, compare_method = compare_method, lhs = lhs, rhs = rhs)
                                     }
                                     None => {
                                         match compare_method {
-                                            Some(compare_method) => {
-                                                block_tokens.write_fmt(format_args!("match {compare_method}({field_name}, ___{field_name}) {{ Some(core::cmp::Ordering::Equal) => (), Some(core::cmp::Ordering::Greater) => {{ return Some(core::cmp::Ordering::Greater); }}, Some(core::cmp::Ordering::Less) => {{ return Some(core::cmp::Ordering::Less); }}, None => {{ return None; }} }}", compare_method = compare_method, field_name = field_name)).unwrap();
-                                            }
-                                            None => {
-                                                block_tokens.write_fmt(format_args!("match core::cmp::PartialOrd::partial_cmp({field_name}, ___{field_name}) {{ Some(core::cmp::Ordering::Equal) => (), Some(core::cmp::Ordering::Greater) => {{ return Some(core::cmp::Ordering::Greater); }}, Some(core::cmp::Ordering::Less) => {{ return Some(core::cmp::Ordering::Less); }}, None => {{ return None; }} }}", field_name = field_name)).unwrap();
-                                            }
+                                            Some(compare_method) => format!("match {compare_method}({lhs}, {rhs}) {{ Some(core::cmp::Ordering::Equal) => (), Some(core::cmp::Ordering::Greater) => {{ return Some(core::cmp::Ordering::Greater); }}, Some(core::cmp::Ordering::Less) => {{ return Some(core::cmp::Ordering::Less); }}, None => {{ return None; }} }}", compare_method = compare_method, lhs = lhs, rhs = rhs),
+                                            None => format!("match core::cmp::PartialOrd::partial_cmp({lhs}, {rhs}) {{ Some(core::cmp::Ordering::Equal) => (), Some(core::cmp::Ordering::Greater) => {{ return Some(core::cmp::Ordering::Greater); }}, Some(core::cmp::Ordering::Less) => {{ return Some(core::cmp::Ordering::Less); }}, None => {{ return None; }} }}", lhs = lhs, rhs = rhs),
                                         }
                                     }
-                                }
+                                };
+
+                                let statement = match none {
+                                    FieldAttributeNone::Default => statement,
+                                    FieldAttributeNone::First => create_none_ordering_statement(&self_value, &other_value, &statement, false, "partial_cmp"),
+                                    FieldAttributeNone::Last => create_none_ordering_statement(&self_value, &other_value, &statement, true, "partial_cmp"),
+                                };
+
+                                block_tokens.push_str(&statement);
                             }
 
-                            match_tokens.write_fmt(format_args!("{enum_name}::{variant_ident}{{ {pattern_tokens} }} => {{ if let {enum_name}::{variant_ident} {{ {pattern_2_tokens} }} = other {{ {block_tokens} }} else {{ let other_value = variant_to_integer(other); return core::cmp::PartialOrd::partial_cmp(&{variant_value}, &other_value); }} }}", enum_name = enum_name, variant_ident = variant_ident, pattern_tokens = pattern_tokens, pattern_2_tokens = pattern_2_tokens, block_tokens = block_tokens, variant_value = variant_value)).unwrap();
+                            match_tokens.write_fmt(format_args!("{enum_name}::{variant_ident}{{ {pattern_tokens} }} => {{ if let {enum_name}::{variant_ident} {{ {pattern_2_tokens} }} = other {{ {block_tokens} }} else {{ {cross_variant_statement} }} }}", enum_name = enum_name, variant_ident = variant_ident, pattern_tokens = pattern_tokens, pattern_2_tokens = pattern_2_tokens, block_tokens = block_tokens, cross_variant_statement = cross_variant_statement)).unwrap();
                         }
                         Fields::Unnamed(fields) => {
                             // TODO Tuple
@@ -233,17 +399,37 @@ impl TraitHandler for PartialOrdEnumHandler {
                             let mut field_names = BTreeMap::new();
 
                             for (index, field) in fields.unnamed.iter().enumerate() {
-                                let field_attribute = FieldAttributeBuilder {
+                                let mut field_attribute = FieldAttributeBuilder {
                                     enable_ignore: true,
                                     enable_impl: true,
                                     rank: isize::min_value() + index as isize,
                                     enable_rank: true,
+                                    enable_reverse: true,
+                                    enable_none: true,
+                                    enable_total: true,
+                                    enable_by: true,
+                                    enable_ptr: true,
                                 }
                                 .from_attributes(&field.attrs, traits);
 
+                                if field_attribute.total {
+                                    field_attribute.compare_method =
+                                        Some(create_total_float_method(&field.ty, "partial_cmp"));
+                                }
+
+                                if let Some(by) = field_attribute.by.as_ref() {
+                                    field_attribute.compare_method =
+                                        Some(create_by_method(&field.ty, by, "partial_cmp"));
+                                }
+
+                                if field_attribute.ptr {
+                                    field_attribute.compare_method =
+                                        Some(create_ptr_method(&field.ty, "partial_cmp"));
+                                }
+
                                 let field_name = format!("{}", index);
 
-                                if field_attribute.ignore {
+                                if type_attribute.variant_only || field_attribute.ignore {
                                     pattern_tokens.push_str("_,");
                                     pattern_2_tokens.push_str("_,");
                                     continue;
@@ -275,29 +461,51 @@ impl TraitHandler for PartialOrdEnumHandler {
                             for (index, field_attribute) in field_attributes {
                                 let field_name = field_names.get(&index).unwrap();
 
+                                let none = field_attribute.none;
+
+                                let self_value = format!("_{}", field_name);
+                                let other_value = format!("__{}", field_name);
+
+                                let (lhs, rhs) = match none {
+                                    FieldAttributeNone::Default => {
+                                        (self_value.as_str(), other_value.as_str())
+                                    }
+                                    _ => ("___self", "___other"),
+                                };
+
+                                let (lhs, rhs) = if variant_reverse ^ field_attribute.reverse {
+                                    (rhs, lhs)
+                                } else {
+                                    (lhs, rhs)
+                                };
+
                                 let compare_trait = field_attribute.compare_trait;
                                 let compare_method = field_attribute.compare_method;
 
-                                match compare_trait {
+                                let statement = match compare_trait {
                                     Some(compare_trait) => {
                                         let compare_method = compare_method.unwrap();
 
-                                        block_tokens.write_fmt(format_args!("match {compare_trait}::{compare_method}(_{field_name}, __{field_name}) {{ Some(core::cmp::Ordering::Equal) => (), Some(core::cmp::Ordering::Greater) => {{ return Some(core::cmp::Ordering::Greater); }}, Some(core::cmp::Ordering::Less) => {{ return Some(core::cmp::Ordering::Less); }}, None => {{ return None; }} }}", compare_trait = compare_trait, compare_method = compare_method, field_name = field_name)).unwrap();
+                                        format!("match {compare_trait}::{compare_method}({lhs}, {rhs}) {{ Some(core::cmp::Ordering::Equal) => (), Some(core::cmp::Ordering::Greater) => {{ return Some(core::cmp::Ordering::Greater); }}, Some(core::cmp::Ordering::Less) => {{ return Some(core::cmp::Ordering::Less); }}, None => {{ return None; }} }}", compare_trait = compare_trait, compare_method = compare_method, lhs = lhs, rhs = rhs)
                                     }
                                     None => {
                                         match compare_method {
-                                            Some(compare_method) => {
-                                                block_tokens.write_fmt(format_args!("match {compare_method}(_{field_name}, __{field_name}) {{ Some(core::cmp::Ordering::Equal) => (), Some(core::cmp::Ordering::Greater) => {{ return Some(core::cmp::Ordering::Greater); }}, Some(core::cmp::Ordering::Less) => {{ return Some(core::cmp::Ordering::Less); }}, None => {{ return None; }} }}", compare_method = compare_method, field_name = field_name)).unwrap();
-                                            }
-                                            None => {
-                                                block_tokens.write_fmt(format_args!("match core::cmp::PartialOrd::partial_cmp(_{field_name}, __{field_name}) {{ Some(core::cmp::Ordering::Equal) => (), Some(core::cmp::Ordering::Greater) => {{ return Some(core::cmp::Ordering::Greater); }}, Some(core::cmp::Ordering::Less) => {{ return Some(core::cmp::Ordering::Less); }}, None => {{ return None; }} }}", field_name = field_name)).unwrap();
-                                            }
+                                            Some(compare_method) => format!("match {compare_method}({lhs}, {rhs}) {{ Some(core::cmp::Ordering::Equal) => (), Some(core::cmp::Ordering::Greater) => {{ return Some(core::cmp::Ordering::Greater); }}, Some(core::cmp::Ordering::Less) => {{ return Some(core::cmp::Ordering::Less); }}, None => {{ return None; }} }}", compare_method = compare_method, lhs = lhs, rhs = rhs),
+                                            None => format!("match core::cmp::PartialOrd::partial_cmp({lhs}, {rhs}) {{ Some(core::cmp::Ordering::Equal) => (), Some(core::cmp::Ordering::Greater) => {{ return Some(core::cmp::Ordering::Greater); }}, Some(core::cmp::Ordering::Less) => {{ return Some(core::cmp::Ordering::Less); }}, None => {{ return None; }} }}", lhs = lhs, rhs = rhs),
                                         }
                                     }
-                                }
+                                };
+
+                                let statement = match none {
+                                    FieldAttributeNone::Default => statement,
+                                    FieldAttributeNone::First => create_none_ordering_statement(&self_value, &other_value, &statement, false, "partial_cmp"),
+                                    FieldAttributeNone::Last => create_none_ordering_statement(&self_value, &other_value, &statement, true, "partial_cmp"),
+                                };
+
+                                block_tokens.push_str(&statement);
                             }
 
-                            match_tokens.write_fmt(format_args!("{enum_name}::{variant_ident}( {pattern_tokens} ) => {{ if let {enum_name}::{variant_ident} ( {pattern_2_tokens} ) = other {{ {block_tokens} }} else {{ let other_value = variant_to_integer(other); return core::cmp::PartialOrd::partial_cmp(&{variant_value}, &other_value); }} }}", enum_name = enum_name, variant_ident = variant_ident, pattern_tokens = pattern_tokens, pattern_2_tokens = pattern_2_tokens, block_tokens = block_tokens, variant_value = variant_value)).unwrap();
+                            match_tokens.write_fmt(format_args!("{enum_name}::{variant_ident}( {pattern_tokens} ) => {{ if let {enum_name}::{variant_ident} ( {pattern_2_tokens} ) = other {{ {block_tokens} }} else {{ {cross_variant_statement} }} }}", enum_name = enum_name, variant_ident = variant_ident, pattern_tokens = pattern_tokens, pattern_2_tokens = pattern_2_tokens, block_tokens = block_tokens, cross_variant_statement = cross_variant_statement)).unwrap();
                         }
                     }
                 }
@@ -309,7 +517,21 @@ impl TraitHandler for PartialOrdEnumHandler {
                 for (index, _) in variants.into_iter().enumerate() {
                     let variant_ident = &variant_idents[index];
 
-                    match_tokens.write_fmt(format_args!("{enum_name}::{variant_ident} => {{ let other_value = unit_to_integer(other); return core::cmp::PartialOrd::partial_cmp(&({enum_name}::{variant_ident} as isize), &other_value); }}", enum_name = enum_name, variant_ident = variant_ident)).unwrap();
+                    let variant_order = if type_attribute.reverse {
+                        format!(
+                            "&other_value, &({enum_name}::{variant_ident} as isize)",
+                            enum_name = enum_name,
+                            variant_ident = variant_ident
+                        )
+                    } else {
+                        format!(
+                            "&({enum_name}::{variant_ident} as isize), &other_value",
+                            enum_name = enum_name,
+                            variant_ident = variant_ident
+                        )
+                    };
+
+                    match_tokens.write_fmt(format_args!("{enum_name}::{variant_ident} => {{ let other_value = unit_to_integer(other); return core::cmp::PartialOrd::partial_cmp({variant_order}); }}", enum_name = enum_name, variant_ident = variant_ident)).unwrap();
                 }
             }
         }
@@ -1,8 +1,12 @@
 use std::collections::BTreeMap;
 use std::str::FromStr;
 
-use super::super::TraitHandler;
-use super::models::{FieldAttributeBuilder, TypeAttributeBuilder};
+use super::super::{
+    create_by_method, create_none_ordering_statement, create_ptr_method, create_rhs_value,
+    create_total_float_method, TraitHandler,
+};
+use super::create_via_ord_impl;
+use super::models::{FieldAttributeBuilder, FieldAttributeNone, TypeAttributeBuilder};
 
 use crate::panic;
 use crate::proc_macro2::TokenStream;
@@ -21,34 +25,72 @@ impl TraitHandler for PartialOrdStructHandler {
         let type_attribute = TypeAttributeBuilder {
             enable_flag: true,
             enable_bound: true,
+            enable_bytes: false,
+            enable_variant_only: false,
+            enable_rhs: true,
+            enable_via_ord: true,
+            enable_cross_variant: false,
             rank: 0,
             enable_rank: false,
+            enable_reverse: true,
         }
         .from_partial_ord_meta(meta);
 
+        if type_attribute.via_ord {
+            return create_via_ord_impl(ast, tokens, type_attribute);
+        }
+
         let bound = type_attribute
             .bound
             .into_punctuated_where_predicates_by_generic_parameters(&ast.generics.params);
 
         let mut comparer_tokens = TokenStream::new();
+        let mut rhs_comparer_tokens = TokenStream::new();
 
         if let Data::Struct(data) = &ast.data {
             let mut field_attributes = BTreeMap::new();
             let mut field_names = BTreeMap::new();
 
             for (index, field) in data.fields.iter().enumerate() {
-                let field_attribute = FieldAttributeBuilder {
+                let mut field_attribute = FieldAttributeBuilder {
                     enable_ignore: true,
                     enable_impl: true,
                     rank: isize::min_value() + index as isize,
                     enable_rank: true,
+                    enable_reverse: true,
+                    enable_none: true,
+                    enable_total: true,
+                    enable_by: true,
+                    enable_ptr: true,
                 }
                 .from_attributes(&field.attrs, traits);
 
+                if field_attribute.total {
+                    field_attribute.compare_method =
+                        Some(create_total_float_method(&field.ty, "partial_cmp"));
+                }
+
+                if let Some(by) = field_attribute.by.as_ref() {
+                    field_attribute.compare_method =
+                        Some(create_by_method(&field.ty, by, "partial_cmp"));
+                }
+
+                if field_attribute.ptr {
+                    field_attribute.compare_method =
+                        Some(create_ptr_method(&field.ty, "partial_cmp"));
+                }
+
                 if field_attribute.ignore {
                     continue;
                 }
 
+                if !type_attribute.rhs.is_empty()
+                    && (field_attribute.compare_method.is_some()
+                        || field_attribute.none != FieldAttributeNone::Default)
+                {
+                    panic::set_rhs_with_field_settings();
+                }
+
                 let rank = field_attribute.rank;
 
                 if field_attributes.contains_key(&rank) {
@@ -68,32 +110,52 @@ impl TraitHandler for PartialOrdStructHandler {
             for (index, field_attribute) in field_attributes {
                 let field_name = field_names.get(&index).unwrap();
 
+                let none = field_attribute.none;
+
+                let self_value = format!("&self.{}", field_name);
+                let other_value = format!("&other.{}", field_name);
+
+                let (lhs, rhs) = match none {
+                    FieldAttributeNone::Default => (self_value.as_str(), other_value.as_str()),
+                    _ => ("___self", "___other"),
+                };
+
+                let reverse = type_attribute.reverse ^ field_attribute.reverse;
+
+                let rhs_statement = format!("match core::cmp::PartialOrd::partial_cmp(&self.{field_name}, {other_value}){reverse} {{ Some(core::cmp::Ordering::Equal) => (), Some(core::cmp::Ordering::Greater) => {{ return Some(core::cmp::Ordering::Greater); }}, Some(core::cmp::Ordering::Less) => {{ return Some(core::cmp::Ordering::Less); }}, None => {{ return None; }} }}", field_name = field_name, other_value = create_rhs_value(&data.fields, field_name), reverse = if reverse { ".map(core::cmp::Ordering::reverse)" } else { "" });
+
+                rhs_comparer_tokens.extend(TokenStream::from_str(&rhs_statement).unwrap());
+
+                let (lhs, rhs) = if reverse {
+                    (rhs, lhs)
+                } else {
+                    (lhs, rhs)
+                };
+
                 let compare_trait = field_attribute.compare_trait;
                 let compare_method = field_attribute.compare_method;
 
-                match compare_trait {
+                let statement = match compare_trait {
                     Some(compare_trait) => {
                         let compare_method = compare_method.unwrap();
 
-                        let statement = format!("match {compare_trait}::{compare_method}(&self.{field_name}, &other.{field_name}) {{ Some(core::cmp::Ordering::Equal) => (), Some(core::cmp::Ordering::Greater) => {{ return Some(core::cmp::Ordering::Greater); }}, Some(core::cmp::Ordering::Less) => {{ return Some(core::cmp::Ordering::Less); }}, None => {{ return None; }} }}", compare_trait = compare_trait, compare_method = compare_method, field_name = field_name);
-
-                        comparer_tokens.extend(TokenStream::from_str(&statement).unwrap());
+                        format!("match {compare_trait}::{compare_method}({lhs}, {rhs}) {{ Some(core::cmp::Ordering::Equal) => (), Some(core::cmp::Ordering::Greater) => {{ return Some(core::cmp::Ordering::Greater); }}, Some(core::cmp::Ordering::Less) => {{ return Some(core::cmp::Ordering::Less); }}, None => {{ return None; }} }}", compare_trait = compare_trait, compare_method = compare_method, lhs = lhs, rhs = rhs)
                     }
                     None => {
                         match compare_method {
-                            Some(compare_method) => {
-                                let statement = format!("match {compare_method}(&self.{field_name}, &other.{field_name}) {{ Some(core::cmp::Ordering::Equal) => (), Some(core::cmp::Ordering::Greater) => {{ return Some(core::cmp::Ordering::Greater); }}, Some(core::cmp::Ordering::Less) => {{ return Some(core::cmp::Ordering::Less); }}, None => {{ return None; }} }}", compare_method = compare_method, field_name = field_name);
-
-                                comparer_tokens.extend(TokenStream::from_str(&statement).unwrap());
-                            }
-                            None => {
-                                let statement = format!("match core::cmp::PartialOrd::partial_cmp(&self.{field_name}, &other.{field_name}) {{ Some(core::cmp::Ordering::Equal) => (), Some(core::cmp::Ordering::Greater) => {{ return Some(core::cmp::Ordering::Greater); }}, Some(core::cmp::Ordering::Less) => {{ return Some(core::cmp::Ordering::Less); }}, None => {{ return None; }} }}", field_name = field_name);
-
-                                comparer_tokens.extend(TokenStream::from_str(&statement).unwrap());
-                            }
+                            Some(compare_method) => format!("match {compare_method}({lhs}, {rhs}) {{ Some(core::cmp::Ordering::Equal) => (), Some(core::cmp::Ordering::Greater) => {{ return Some(core::cmp::Ordering::Greater); }}, Some(core::cmp::Ordering::Less) => {{ return Some(core::cmp::Ordering::Less); }}, None => {{ return None; }} }}", compare_method = compare_method, lhs = lhs, rhs = rhs),
+                            None => format!("match core::cmp::PartialOrd::partial_cmp({lhs}, {rhs}) {{ Some(core::cmp::Ordering::Equal) => (), Some(core::cmp::Ordering::Greater) => {{ return Some(core::cmp::Ordering::Greater); }}, Some(core::cmp::Ordering::Less) => {{ return Some(core::cmp::Ordering::Less); }}, None => {{ return None; }} }}", lhs = lhs, rhs = rhs),
                         }
                     }
-                }
+                };
+
+                let statement = match none {
+                    FieldAttributeNone::Default => statement,
+                    FieldAttributeNone::First => create_none_ordering_statement(&self_value, &other_value, &statement, false, "partial_cmp"),
+                    FieldAttributeNone::Last => create_none_ordering_statement(&self_value, &other_value, &statement, true, "partial_cmp"),
+                };
+
+                comparer_tokens.extend(TokenStream::from_str(&statement).unwrap());
             }
         }
 
@@ -121,5 +183,20 @@ impl TraitHandler for PartialOrdStructHandler {
         };
 
         tokens.extend(compare_impl);
+
+        for rhs in type_attribute.rhs.iter() {
+            let compare_impl = quote! {
+                impl #impl_generics core::cmp::PartialOrd<#rhs> for #ident #ty_generics #where_clause {
+                    #[inline]
+                    fn partial_cmp(&self, other: &#rhs) -> Option<core::cmp::Ordering> {
+                        #rhs_comparer_tokens
+
+                        Some(core::cmp::Ordering::Equal)
+                    }
+                }
+            };
+
+            tokens.extend(compare_impl);
+        }
     }
 }
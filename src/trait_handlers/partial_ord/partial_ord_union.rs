@@ -0,0 +1,92 @@
+use super::super::{create_union_bytes_check, TraitHandler};
+use super::create_via_ord_impl;
+use super::models::{FieldAttributeBuilder, TypeAttributeBuilder};
+
+use crate::panic;
+use crate::proc_macro2::TokenStream;
+use crate::syn::{Data, DeriveInput, Generics, Meta};
+use crate::Trait;
+
+pub struct PartialOrdUnionHandler;
+
+impl TraitHandler for PartialOrdUnionHandler {
+    fn trait_meta_handler(
+        ast: &DeriveInput,
+        tokens: &mut TokenStream,
+        traits: &[Trait],
+        meta: &Meta,
+    ) {
+        let type_attribute = TypeAttributeBuilder {
+            enable_flag: true,
+            enable_bound: true,
+            enable_bytes: true,
+            enable_variant_only: false,
+            enable_rhs: false,
+            enable_via_ord: true,
+            enable_cross_variant: false,
+            rank: 0,
+            enable_rank: false,
+            enable_reverse: false,
+        }
+        .from_partial_ord_meta(meta);
+
+        if type_attribute.via_ord {
+            return create_via_ord_impl(ast, tokens, type_attribute);
+        }
+
+        if !type_attribute.bytes {
+            panic::trait_not_support_union(Trait::PartialOrd);
+        }
+
+        let bound = type_attribute
+            .bound
+            .into_punctuated_where_predicates_by_generic_parameters(&ast.generics.params);
+
+        if let Data::Union(data) = &ast.data {
+            for (index, field) in data.fields.named.iter().enumerate() {
+                let _ = FieldAttributeBuilder {
+                    enable_ignore: false,
+                    enable_impl: false,
+                    rank: isize::MIN + index as isize,
+                    enable_rank: false,
+                    enable_reverse: false,
+                    enable_none: false,
+                    enable_total: false,
+                    enable_by: false,
+                    enable_ptr: false,
+                }
+                .from_attributes(&field.attrs, traits);
+            }
+        }
+
+        tokens.extend(create_union_bytes_check(ast));
+
+        let ident = &ast.ident;
+
+        let mut generics_cloned: Generics = ast.generics.clone();
+
+        let where_clause = generics_cloned.make_where_clause();
+
+        for where_predicate in bound {
+            where_clause.predicates.push(where_predicate);
+        }
+
+        let (impl_generics, ty_generics, where_clause) = generics_cloned.split_for_impl();
+
+        let compare_impl = quote! {
+            impl #impl_generics core::cmp::PartialOrd for #ident #ty_generics #where_clause {
+                #[inline]
+                fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                    let size = core::mem::size_of::<Self>();
+
+                    let a = unsafe { core::slice::from_raw_parts(self as *const Self as *const u8, size) };
+                    let b = unsafe { core::slice::from_raw_parts(other as *const Self as *const u8, size) };
+
+                    core::cmp::PartialOrd::partial_cmp(a, b)
+                }
+            }
+        };
+
+        tokens.extend(compare_impl);
+    }
+}
@@ -0,0 +1,91 @@
+#![cfg(feature = "Clone")]
+#![no_std]
+
+extern crate alloc;
+
+#[macro_use]
+extern crate educe;
+
+use alloc::string::String;
+use core::mem::ManuallyDrop;
+
+#[test]
+fn union_tag_1() {
+    #[derive(Clone, Copy)]
+    #[repr(C)]
+    struct Int {
+        tag: u8,
+        value: u32,
+    }
+
+    #[derive(Clone)]
+    #[repr(C)]
+    struct Text {
+        tag: u8,
+        value: String,
+    }
+
+    #[derive(Educe)]
+    #[educe(Clone, union_tag = "Self::active_field")]
+    union Union {
+        int: Int,
+        text: ManuallyDrop<Text>,
+    }
+
+    impl Union {
+        fn active_field(&self) -> &'static str {
+            match unsafe { self.int.tag } {
+                0 => "int",
+                _ => "text",
+            }
+        }
+    }
+
+    let mut u = Union {
+        text: ManuallyDrop::new(Text {
+            tag: 1,
+            value: String::from("hello"),
+        }),
+    };
+
+    let mut cloned = u.clone();
+
+    assert_eq!("hello", unsafe { cloned.text.value.as_str() });
+
+    unsafe {
+        ManuallyDrop::drop(&mut cloned.text);
+        ManuallyDrop::drop(&mut u.text);
+    }
+
+    let u = Union {
+        int: Int {
+            tag: 0,
+            value: 1,
+        },
+    };
+
+    assert_eq!(1, unsafe { u.clone().int.value });
+}
+
+#[test]
+fn clone_field_1() {
+    #[derive(Educe)]
+    #[educe(Clone)]
+    union Union {
+        #[educe(Clone)]
+        f1: ManuallyDrop<String>,
+    }
+
+    let mut u = Union {
+        f1: ManuallyDrop::new(String::from("hello")),
+    };
+
+    let mut cloned = u.clone();
+
+    assert_eq!("hello", unsafe { cloned.f1.as_str() });
+
+    unsafe {
+        ManuallyDrop::drop(&mut cloned.f1);
+        ManuallyDrop::drop(&mut u.f1);
+    }
+}
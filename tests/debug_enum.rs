@@ -591,6 +591,134 @@ fn rename_variant_6() {
     assert_eq!("Enum::Hi(1)", format!("{:?}", Enum::Tuple(1)));
 }
 
+#[test]
+fn rename_all_1() {
+    #[derive(Educe)]
+    #[educe(Debug(rename_all = "SCREAMING_SNAKE_CASE"))]
+    enum Enum {
+        FirstUnit,
+        #[educe(Debug(name = "S"))]
+        SecondStruct { field_one: u8 },
+        ThirdTuple(u8),
+    }
+
+    assert_eq!("FIRST_UNIT", format!("{:?}", Enum::FirstUnit));
+    assert_eq!(
+        "S { field_one: 1 }",
+        format!("{:?}", Enum::SecondStruct {
+            field_one: 1
+        })
+    );
+    assert_eq!("THIRD_TUPLE(1)", format!("{:?}", Enum::ThirdTuple(1)));
+}
+
+#[test]
+fn rename_all_2() {
+    #[derive(Educe)]
+    #[educe(Debug(name = true, rename_all = "kebab-case"))]
+    enum Enum {
+        #[educe(Debug(rename_all = "camelCase"))]
+        StructVariant {
+            field_one: u8,
+            #[educe(Debug(name = "f"))]
+            field_two: u8,
+        },
+    }
+
+    assert_eq!(
+        "Enum::struct-variant { fieldOne: 1, f: 2 }",
+        format!("{:?}", Enum::StructVariant {
+            field_one: 1,
+            field_two: 2,
+        })
+    );
+}
+
+#[test]
+fn style_1() {
+    #[derive(Educe)]
+    #[educe(Debug(style = "qualified"))]
+    enum Enum {
+        Unit,
+        Struct { f1: u8 },
+        Tuple(u8),
+    }
+
+    assert_eq!("Enum::Unit", format!("{:?}", Enum::Unit));
+    assert_eq!(
+        "Enum::Struct { f1: 1 }",
+        format!("{:?}", Enum::Struct {
+            f1: 1
+        })
+    );
+    assert_eq!("Enum::Tuple(1)", format!("{:?}", Enum::Tuple(1)));
+}
+
+#[test]
+fn style_2() {
+    #[derive(Educe)]
+    #[educe(Debug(style = "variant"))]
+    enum Enum {
+        Unit,
+        Tuple(u8),
+    }
+
+    assert_eq!("Unit", format!("{:?}", Enum::Unit));
+    assert_eq!("Tuple(1)", format!("{:?}", Enum::Tuple(1)));
+}
+
+#[test]
+fn style_3() {
+    #[derive(Educe)]
+    #[educe(Debug(style = "discriminant"))]
+    enum Enum {
+        Zero,
+        Ten = 10,
+        Eleven,
+        #[educe(Debug(name = "Named"))]
+        Twelve,
+    }
+
+    assert_eq!("0", format!("{:?}", Enum::Zero));
+    assert_eq!("10", format!("{:?}", Enum::Ten));
+    assert_eq!("11", format!("{:?}", Enum::Eleven));
+    assert_eq!("Named", format!("{:?}", Enum::Twelve));
+}
+
+#[test]
+fn style_4() {
+    #[derive(Educe)]
+    #[educe(Debug(style("discriminant")))]
+    #[repr(u8)]
+    enum Enum {
+        A = 0x80,
+        B = 0xFF,
+    }
+
+    assert_eq!("128", format!("{:?}", Enum::A));
+    assert_eq!("255", format!("{:?}", Enum::B));
+}
+
+#[test]
+fn with_generics_1() {
+    #[derive(Educe)]
+    #[educe(Debug(style = "qualified", with_generics, bound))]
+    enum Enum<T> {
+        Unit,
+        Struct { f1: T },
+        Tuple(T),
+    }
+
+    assert_eq!("Enum<u8>::Unit", format!("{:?}", Enum::<u8>::Unit));
+    assert_eq!(
+        "Enum<u8>::Struct { f1: 1 }",
+        format!("{:?}", Enum::Struct {
+            f1: 1u8
+        })
+    );
+    assert_eq!("Enum<u8>::Tuple(1)", format!("{:?}", Enum::Tuple(1u8)));
+}
+
 #[test]
 #[allow(dead_code)]
 fn rename_field_1() {
@@ -755,6 +883,78 @@ fn ignore_3() {
     assert_eq!("Tuple(2)", format!("{:?}", Enum::Tuple(1, 2)));
 }
 
+#[test]
+#[allow(dead_code)]
+fn non_exhaustive_1() {
+    #[derive(Educe)]
+    #[educe(Debug(non_exhaustive))]
+    enum Enum {
+        Struct {
+            #[educe(Debug(ignore))]
+            f1: u8,
+            f2: u8,
+        },
+        Tuple(#[educe(Debug(ignore))] u8, u8),
+        Full(u8),
+    }
+
+    assert_eq!(
+        "Struct { f2: 2, .. }",
+        format!("{:?}", Enum::Struct {
+            f1: 1,
+            f2: 2
+        })
+    );
+    assert_eq!("Tuple(2, ..)", format!("{:?}", Enum::Tuple(1, 2)));
+    assert_eq!("Full(1)", format!("{:?}", Enum::Full(1)));
+}
+
+#[test]
+#[allow(dead_code)]
+fn non_exhaustive_2() {
+    #[derive(Educe)]
+    #[educe(Debug(non_exhaustive))]
+    enum Enum {
+        #[educe(Debug(non_exhaustive = false))]
+        Struct {
+            #[educe(Debug(ignore))]
+            f1: u8,
+            f2: u8,
+        },
+        Tuple(#[educe(Debug(ignore))] u8, u8),
+    }
+
+    assert_eq!(
+        "Struct { f2: 2 }",
+        format!("{:?}", Enum::Struct {
+            f1: 1,
+            f2: 2
+        })
+    );
+    assert_eq!("Tuple(2, ..)", format!("{:?}", Enum::Tuple(1, 2)));
+
+    #[derive(Educe)]
+    #[educe(Debug)]
+    enum Enum2 {
+        #[educe(Debug(non_exhaustive))]
+        Struct {
+            #[educe(Debug(ignore))]
+            f1: u8,
+            f2: u8,
+        },
+        Tuple(#[educe(Debug(ignore))] u8, u8),
+    }
+
+    assert_eq!(
+        "Struct { f2: 2, .. }",
+        format!("{:?}", Enum2::Struct {
+            f1: 1,
+            f2: 2
+        })
+    );
+    assert_eq!("Tuple(2)", format!("{:?}", Enum2::Tuple(1, 2)));
+}
+
 #[test]
 #[allow(dead_code)]
 fn format_without_trait_1() {
@@ -811,6 +1011,117 @@ fn format_without_trait_2() {
     assert_eq!("Tuple(Hi)", format!("{:?}", Enum::Tuple(1)));
 }
 
+#[test]
+fn max_items_1() {
+    #[derive(Educe)]
+    #[educe(Debug)]
+    enum Enum {
+        Struct {
+            #[educe(Debug(max_items = 3))]
+            f1: alloc::vec::Vec<u8>,
+        },
+        Tuple(#[educe(Debug(max_items = 1))] alloc::vec::Vec<u8>),
+    }
+
+    assert_eq!(
+        "Struct { f1: [1, 2, 3, ... (2 more)] }",
+        format!("{:?}", Enum::Struct {
+            f1: vec![1, 2, 3, 4, 5]
+        })
+    );
+    assert_eq!("Tuple([1, ... (1 more)])", format!("{:?}", Enum::Tuple(vec![1, 2])));
+}
+
+#[test]
+fn max_len_1() {
+    #[derive(Educe)]
+    #[educe(Debug)]
+    enum Enum {
+        Struct {
+            #[educe(Debug(max_len = 5))]
+            f1: alloc::string::String,
+        },
+        Tuple(#[educe(Debug(max_len = 5))] &'static str),
+    }
+
+    assert_eq!(
+        "Struct { f1: \"hello\"... (6 more) }",
+        format!("{:?}", Enum::Struct {
+            f1: alloc::string::String::from("hello world")
+        })
+    );
+    assert_eq!("Tuple(\"hi\")", format!("{:?}", Enum::Tuple("hi")));
+}
+
+#[test]
+fn sorted_1() {
+    use alloc::vec::Vec;
+
+    #[derive(Educe)]
+    #[educe(Debug)]
+    enum Enum {
+        Struct {
+            #[educe(Debug(sorted))]
+            f1: Vec<u8>,
+        },
+        Tuple(#[educe(Debug(sorted))] Vec<u8>),
+    }
+
+    assert_eq!(
+        "Struct { f1: {1, 2, 3} }",
+        format!("{:?}", Enum::Struct {
+            f1: vec![2, 3, 1]
+        })
+    );
+    assert_eq!("Tuple({1, 2})", format!("{:?}", Enum::Tuple(vec![2, 1])));
+}
+
+#[test]
+fn flatten_1() {
+    #[derive(Educe)]
+    #[educe(Debug)]
+    struct Header {
+        id: u8,
+        ts: u8,
+    }
+
+    #[derive(Educe)]
+    #[educe(Debug)]
+    enum Enum {
+        Struct {
+            #[educe(Debug(flatten))]
+            header: Header,
+            body: u8,
+        },
+        #[educe(Debug(named_field = true))]
+        Tuple(#[educe(Debug(flatten))] Header, u8),
+    }
+
+    assert_eq!(
+        "Struct { id: 1, ts: 2, body: 3 }",
+        format!("{:?}", Enum::Struct {
+            header: Header {
+                id: 1,
+                ts: 2,
+            },
+            body: 3,
+        })
+    );
+    assert_eq!(
+        "Tuple { id: 1, ts: 2, _1: 3 }",
+        format!(
+            "{:?}",
+            Enum::Tuple(
+                Header {
+                    id: 1,
+                    ts: 2,
+                },
+                3
+            )
+        )
+    );
+}
+
 #[test]
 #[allow(dead_code)]
 fn format_with_trait_1() {
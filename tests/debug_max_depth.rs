@@ -0,0 +1,120 @@
+#![cfg(feature = "Debug")]
+#![no_std]
+
+#[macro_use]
+extern crate alloc;
+
+#[macro_use]
+extern crate educe;
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+#[test]
+fn max_depth_1() {
+    #[derive(Educe)]
+    #[educe(Debug(max_depth = 2))]
+    enum Expr {
+        Literal(u8),
+        Neg(Box<Expr>),
+        Add { lhs: Box<Expr>, rhs: Box<Expr> },
+    }
+
+    let expr = Expr::Add {
+        lhs: Box::new(Expr::Literal(1)),
+        rhs: Box::new(Expr::Neg(Box::new(Expr::Literal(2)))),
+    };
+
+    assert_eq!("Add { lhs: Literal(1), rhs: Neg(..) }", format!("{:?}", expr));
+    assert_eq!(
+        "Add {\n    lhs: Literal(\n        1,\n    ),\n    rhs: Neg(\n        ..,\n    ),\n}",
+        format!("{:#?}", expr)
+    );
+
+    // The depth is not kept between calls.
+    assert_eq!("Add { lhs: Literal(1), rhs: Neg(..) }", format!("{:?}", expr));
+}
+
+#[test]
+fn max_depth_2() {
+    #[derive(Educe)]
+    #[educe(Debug(max_depth(1)))]
+    struct Node {
+        value: u8,
+        children: Vec<Node>,
+    }
+
+    let node = Node {
+        value: 1,
+        children: vec![Node {
+            value: 2,
+            children: vec![],
+        }],
+    };
+
+    assert_eq!("Node { value: 1, children: [..] }", format!("{:?}", node));
+}
+
+#[test]
+fn max_depth_3() {
+    #[derive(Educe)]
+    #[educe(Debug(max_depth = 2))]
+    enum Expr {
+        Literal(u8),
+        Block(Vec<Stmt>),
+    }
+
+    #[derive(Educe)]
+    #[educe(Debug)]
+    enum Stmt {
+        Expr(Expr),
+    }
+
+    let expr = Expr::Block(vec![Stmt::Expr(Expr::Block(vec![Stmt::Expr(Expr::Literal(1))]))]);
+
+    assert_eq!("Block([Expr(Block([Expr(..)]))])", format!("{:?}", expr));
+}
+
+#[test]
+fn max_depth_4() {
+    #[derive(Educe)]
+    #[educe(Debug(max_depth = 2))]
+    enum Expr {
+        Literal(u8),
+        Block(Vec<Stmt>),
+    }
+
+    #[derive(Educe)]
+    #[educe(Debug(max_depth = 2))]
+    enum Stmt {
+        Expr(Expr),
+    }
+
+    let expr = Expr::Block(vec![Stmt::Expr(Expr::Block(vec![Stmt::Expr(Expr::Literal(1))]))]);
+
+    assert_eq!("Block([Expr(..)])", format!("{:?}", expr));
+    assert_eq!(
+        "Expr(Block([..]))",
+        format!("{:?}", Stmt::Expr(Expr::Block(vec![Stmt::Expr(Expr::Literal(1))])))
+    );
+}
+
+#[test]
+fn max_depth_5() {
+    #[derive(Educe)]
+    #[educe(Debug(max_depth = 64))]
+    struct Point {
+        x: f64,
+        y: u8,
+    }
+
+    let point = Point {
+        x: 1.2345,
+        y: 255,
+    };
+
+    assert_eq!("Point { x: 1.2, y: 255 }", format!("{:.1?}", point));
+    assert_eq!("Point {\n    x: 1.2,\n    y: 255,\n}", format!("{:#.1?}", point));
+    assert_eq!("Point { x: 1.2345, y:  255 }", format!("{:4?}", point));
+    assert_eq!("Point { x: +1.2345, y: +255 }", format!("{:+?}", point));
+}
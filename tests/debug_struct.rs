@@ -416,6 +416,118 @@ fn rename_field_4() {
     assert_eq!("Tuple { f: 1 }", format!("{:?}", Tuple(1)));
 }
 
+#[test]
+fn rename_all_1() {
+    #[derive(Educe)]
+    #[educe(Debug(rename_all = "camelCase"))]
+    struct Struct {
+        field_one: u8,
+        #[educe(Debug(name = "f"))]
+        field_two: u8,
+    }
+
+    assert_eq!(
+        "Struct { fieldOne: 1, f: 2 }",
+        format!("{:?}", Struct {
+            field_one: 1,
+            field_two: 2,
+        })
+    );
+
+    #[derive(Educe)]
+    #[educe(Debug(rename_all("SCREAMING_SNAKE_CASE")))]
+    struct Struct2 {
+        field_one: u8,
+    }
+
+    assert_eq!(
+        "Struct2 { FIELD_ONE: 1 }",
+        format!("{:?}", Struct2 {
+            field_one: 1
+        })
+    );
+}
+
+#[test]
+fn rename_all_2() {
+    #[derive(Educe)]
+    #[educe(Debug(rename_all = "kebab-case"))]
+    struct Struct {
+        field_one: u8,
+    }
+
+    assert_eq!(
+        "Struct { field-one: 1 }",
+        format!("{:?}", Struct {
+            field_one: 1
+        })
+    );
+
+    #[allow(non_snake_case)]
+    #[derive(Educe)]
+    #[educe(Debug(rename_all = "snake_case"))]
+    struct Struct2 {
+        fieldOne: u8,
+        HTTPServer: u8,
+    }
+
+    assert_eq!(
+        "Struct2 { field_one: 1, http_server: 2 }",
+        format!("{:?}", Struct2 {
+            fieldOne: 1,
+            HTTPServer: 2,
+        })
+    );
+}
+
+#[test]
+fn with_generics_1() {
+    use alloc::string::String;
+
+    #[derive(Educe)]
+    #[educe(Debug(with_generics, bound))]
+    struct Struct<T> {
+        f1: T,
+    }
+
+    #[derive(Educe)]
+    #[educe(Debug(with_generics, bound))]
+    struct Tuple<'a, T, const N: usize>(&'a [T; N]);
+
+    assert_eq!(
+        "Struct<u8> { f1: 1 }",
+        format!("{:?}", Struct {
+            f1: 1u8
+        })
+    );
+    assert_eq!(
+        "Struct<alloc::string::String> { f1: \"a\" }",
+        format!("{:?}", Struct {
+            f1: String::from("a")
+        })
+    );
+    assert_eq!("Tuple<u8, 2>([1, 2])", format!("{:?}", Tuple(&[1u8, 2])));
+}
+
+#[test]
+fn with_generics_2() {
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    #[derive(Educe)]
+    #[educe(Debug(name = "Wrapper", with_generics = "short", bound))]
+    struct Struct<T> {
+        f1: T,
+    }
+
+    assert_eq!(
+        "Wrapper<Vec<String>> { f1: [] }",
+        format!("{:?}", Struct {
+            f1: Vec::<String>::new()
+        })
+    );
+}
+
 #[test]
 #[allow(dead_code)]
 fn ignore_1() {
@@ -494,6 +606,67 @@ fn ignore_3() {
     assert_eq!("Tuple(2)", format!("{:?}", Tuple(1, 2)));
 }
 
+#[test]
+#[allow(dead_code)]
+fn non_exhaustive_1() {
+    #[derive(Educe)]
+    #[educe(Debug(non_exhaustive))]
+    struct Struct {
+        #[educe(Debug(ignore))]
+        f1: u8,
+        f2: u8,
+    }
+
+    assert_eq!(
+        "Struct { f2: 2, .. }",
+        format!("{:?}", Struct {
+            f1: 1,
+            f2: 2
+        })
+    );
+
+    #[derive(Educe)]
+    #[educe(Debug(non_exhaustive))]
+    struct Tuple(#[educe(Debug(ignore))] u8, u8);
+
+    assert_eq!("Tuple(2, ..)", format!("{:?}", Tuple(1, 2)));
+}
+
+#[test]
+#[allow(dead_code)]
+fn non_exhaustive_2() {
+    #[derive(Educe)]
+    #[educe(Debug(non_exhaustive = true))]
+    struct Struct {
+        f1: u8,
+        f2: u8,
+    }
+
+    assert_eq!(
+        "Struct { f1: 1, f2: 2 }",
+        format!("{:?}", Struct {
+            f1: 1,
+            f2: 2
+        })
+    );
+
+    #[derive(Educe)]
+    #[educe(Debug(name = false, non_exhaustive(true)))]
+    struct Struct2 {
+        #[educe(Debug(ignore))]
+        f1: u8,
+        f2: u8,
+    }
+
+    assert_eq!(
+        "{f2: 2, ..}",
+        format!("{:?}", Struct2 {
+            f1: 1,
+            f2: 2
+        })
+    );
+}
+
 #[test]
 fn format_without_trait_1() {
     use core::fmt::{self, Formatter};
@@ -552,6 +725,211 @@ fn format_without_trait_2() {
     assert_eq!("Tuple(Hi)", format!("{:?}", Tuple(1)));
 }
 
+#[test]
+fn max_items_1() {
+    #[derive(Educe)]
+    #[educe(Debug)]
+    struct Struct {
+        #[educe(Debug(max_items = 3))]
+        f1: alloc::vec::Vec<u8>,
+        #[educe(Debug(max_items(3)))]
+        f2: [u8; 2],
+    }
+
+    assert_eq!(
+        "Struct { f1: [1, 2, 3, ... (2 more)], f2: [1, 2] }",
+        format!("{:?}", Struct {
+            f1: vec![1, 2, 3, 4, 5],
+            f2: [1, 2],
+        })
+    );
+
+    #[derive(Educe)]
+    #[educe(Debug)]
+    struct Tuple(#[educe(Debug(max_items = 0))] alloc::vec::Vec<u8>);
+
+    assert_eq!("Tuple([... (2 more)])", format!("{:?}", Tuple(vec![1, 2])));
+}
+
+#[test]
+fn max_len_1() {
+    #[derive(Educe)]
+    #[educe(Debug)]
+    struct Struct {
+        #[educe(Debug(max_len = 5))]
+        f1: alloc::string::String,
+        #[educe(Debug(max_len(5)))]
+        f2: &'static str,
+    }
+
+    assert_eq!(
+        "Struct { f1: \"hello\"... (6 more), f2: \"hi\" }",
+        format!("{:?}", Struct {
+            f1: alloc::string::String::from("hello world"),
+            f2: "hi",
+        })
+    );
+
+    #[derive(Educe)]
+    #[educe(Debug)]
+    struct Tuple(#[educe(Debug(max_len = 1))] &'static str);
+
+    assert_eq!("Tuple(\"a\"... (1 more))", format!("{:?}", Tuple("ab")));
+}
+
+#[test]
+fn sorted_1() {
+    use alloc::collections::BTreeMap;
+    use alloc::vec::Vec;
+
+    #[derive(Educe)]
+    #[educe(Debug)]
+    struct Struct {
+        #[educe(Debug(sorted))]
+        f1: Vec<u8>,
+        #[educe(Debug(sorted))]
+        f2: BTreeMap<u8, &'static str>,
+    }
+
+    let mut f2 = BTreeMap::new();
+
+    f2.insert(2, "b");
+    f2.insert(1, "a");
+
+    assert_eq!(
+        "Struct { f1: {1, 2, 3}, f2: {1: \"a\", 2: \"b\"} }",
+        format!("{:?}", Struct {
+            f1: vec![3, 1, 2],
+            f2,
+        })
+    );
+
+    #[derive(Educe)]
+    #[educe(Debug)]
+    struct Tuple(#[educe(Debug(sorted))] Vec<u8>);
+
+    assert_eq!("Tuple({})", format!("{:?}", Tuple(Vec::new())));
+    assert_eq!("Tuple({1, 1, 2, 3})", format!("{:?}", Tuple(vec![3, 1, 1, 2])));
+}
+
+#[test]
+fn flatten_1() {
+    #[derive(Educe)]
+    #[educe(Debug)]
+    struct Header {
+        id: u8,
+        #[educe(Debug(name = "timestamp"))]
+        ts: u8,
+    }
+
+    #[derive(Educe)]
+    #[educe(Debug)]
+    struct Struct {
+        #[educe(Debug(flatten))]
+        header: Header,
+        body: u8,
+    }
+
+    assert_eq!(
+        "Struct { id: 1, timestamp: 2, body: 3 }",
+        format!("{:?}", Struct {
+            header: Header {
+                id: 1,
+                ts: 2,
+            },
+            body: 3,
+        })
+    );
+
+    #[derive(Educe)]
+    #[educe(Debug)]
+    struct Outer {
+        #[educe(Debug(flatten))]
+        inner: Struct,
+    }
+
+    assert_eq!(
+        "Outer { id: 1, timestamp: 2, body: 3 }",
+        format!("{:?}", Outer {
+            inner: Struct {
+                header: Header {
+                    id: 1,
+                    ts: 2,
+                },
+                body: 3,
+            }
+        })
+    );
+}
+
+#[test]
+fn flatten_2() {
+    #[derive(Educe)]
+    #[educe(Debug)]
+    struct Header {
+        id: u8,
+    }
+
+    #[derive(Educe)]
+    #[educe(Debug(name = false))]
+    struct Struct {
+        #[educe(Debug(flatten))]
+        header: Header,
+        body: u8,
+    }
+
+    assert_eq!(
+        "{id: 1, body: 2}",
+        format!("{:?}", Struct {
+            header: Header {
+                id: 1
+            },
+            body: 2,
+        })
+    );
+
+    #[derive(Educe)]
+    #[educe(Debug(named_field = true))]
+    struct Tuple(#[educe(Debug(flatten))] Header, u8);
+
+    assert_eq!(
+        "Tuple { id: 1, _1: 2 }",
+        format!("{:?}", Tuple(
+            Header {
+                id: 1
+            },
+            2
+        ))
+    );
+}
+
+#[test]
+fn flatten_3() {
+    #[derive(Educe)]
+    #[educe(Debug)]
+    struct Header<'a, T: core::fmt::Debug> {
+        id: &'a T,
+    }
+
+    #[derive(Educe)]
+    #[educe(Debug)]
+    struct Struct<'a, T: core::fmt::Debug> {
+        #[educe(Debug(flatten))]
+        header: Header<'a, T>,
+        body: u8,
+    }
+
+    assert_eq!(
+        "Struct { id: 1, body: 2 }",
+        format!("{:?}", Struct {
+            header: Header {
+                id: &1
+            },
+            body: 2,
+        })
+    );
+}
+
 #[test]
 fn format_with_trait_1() {
     use core::fmt::{self, Formatter};
@@ -198,7 +198,7 @@ fn unnamed_6() {
 #[allow(dead_code)]
 fn bound_1() {
     #[derive(Educe)]
-    #[educe(Debug(bound))]
+    #[educe(Debug(bound, unsafe_bytes))]
     union Union<T: Copy> {
         f1: T,
     }
@@ -215,7 +215,7 @@ fn bound_1() {
 #[allow(dead_code)]
 fn bound_2() {
     #[derive(Educe)]
-    #[educe(Debug(bound = "T: core::fmt::Debug"))]
+    #[educe(Debug(bound = "T: core::fmt::Debug", unsafe_bytes))]
     union Union<T: Copy> {
         f1: T,
     }
@@ -232,7 +232,7 @@ fn bound_2() {
 #[allow(dead_code)]
 fn bound_3() {
     #[derive(Educe)]
-    #[educe(Debug(bound("T: core::fmt::Debug")))]
+    #[educe(Debug(bound("T: core::fmt::Debug"), unsafe_bytes))]
     union Union<T: Copy> {
         f1: T,
     }
@@ -244,3 +244,103 @@ fn bound_3() {
         })
     );
 }
+
+#[test]
+#[allow(dead_code)]
+fn format_1() {
+    #[derive(Educe)]
+    #[educe(Debug)]
+    union Union {
+        f1: [u8; 4],
+        f2: u32,
+        f3: f32,
+    }
+
+    assert_eq!(
+        "Union([1, 2, 3, 4])",
+        format!("{:?}", Union {
+            f1: [1, 2, 3, 4]
+        })
+    );
+}
+
+#[test]
+#[allow(dead_code)]
+fn format_2() {
+    #[derive(Educe)]
+    #[educe(Debug(opaque))]
+    union Union {
+        f1: u8,
+        f2: (u8, u16),
+    }
+
+    assert_eq!(
+        "Union { .. }",
+        format!("{:?}", Union {
+            f1: 1
+        })
+    );
+
+    #[derive(Educe)]
+    #[educe(Debug(name = false, opaque))]
+    union Union2 {
+        f1: u8,
+    }
+
+    assert_eq!(
+        "..",
+        format!("{:?}", Union2 {
+            f1: 1
+        })
+    );
+}
+
+#[test]
+fn union_tag_1() {
+    #[derive(Debug, Clone, Copy)]
+    #[repr(C)]
+    struct Small {
+        tag: u8,
+        value: u8,
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    #[repr(C)]
+    struct Large {
+        tag: u8,
+        value: u32,
+    }
+
+    #[derive(Educe)]
+    #[educe(Debug, union_tag = "Self::active_field")]
+    union Union {
+        small: Small,
+        large: Large,
+    }
+
+    impl Union {
+        fn active_field(&self) -> &'static str {
+            match unsafe { self.small.tag } {
+                0 => "small",
+                _ => "large",
+            }
+        }
+    }
+
+    assert_eq!(
+        "Union { small: Small { tag: 0, value: 1 } }",
+        format!("{:?}", Union {
+            small: Small {
+                tag: 0, value: 1
+            }
+        })
+    );
+    assert_eq!(
+        "Union { large: Large { tag: 1, value: 2 } }",
+        format!("{:?}", Union {
+            large: Large {
+                tag: 1, value: 2
+            }
+        })
+    );
+}
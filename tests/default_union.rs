@@ -417,6 +417,56 @@ fn field_default_4() {
     assert_eq!('M', unsafe { Union6::default().f6 });
 }
 
+#[test]
+#[allow(dead_code)]
+fn field_default_manually_drop() {
+    extern crate alloc;
+
+    use alloc::string::String;
+    use core::mem::ManuallyDrop;
+
+    #[derive(Educe)]
+    #[educe(Default)]
+    union Union {
+        f1: u8,
+        #[educe(Default = "Hi")]
+        f2: ManuallyDrop<String>,
+    }
+
+    let mut u = Union::default();
+
+    assert_eq!("Hi", unsafe { u.f2.as_str() });
+
+    unsafe { ManuallyDrop::drop(&mut u.f2) };
+
+    #[derive(Educe)]
+    #[educe(Default)]
+    union Union2 {
+        #[educe(Default(expression = "ManuallyDrop::new(String::from(\"Hello\"))"))]
+        f1: ManuallyDrop<String>,
+    }
+
+    let mut u = Union2::default();
+
+    assert_eq!("Hello", unsafe { u.f1.as_str() });
+
+    unsafe { ManuallyDrop::drop(&mut u.f1) };
+
+    #[derive(Educe)]
+    #[educe(Default)]
+    union Union3 {
+        #[educe(Default)]
+        f1: ManuallyDrop<String>,
+        f2: u8,
+    }
+
+    let mut u = Union3::default();
+
+    assert_eq!("", unsafe { u.f1.as_str() });
+
+    unsafe { ManuallyDrop::drop(&mut u.f1) };
+}
+
 #[test]
 #[allow(dead_code)]
 fn bound_1() {
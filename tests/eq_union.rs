@@ -67,3 +67,14 @@ fn bound_3() {
         }
     }
 }
+
+#[test]
+#[allow(dead_code)]
+fn bytes_1() {
+    #[derive(Educe)]
+    #[educe(PartialEq(bytes), Eq)]
+    union Union {
+        f1: u8,
+        f2: i8,
+    }
+}
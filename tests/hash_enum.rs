@@ -588,3 +588,157 @@ fn bound_3() {
     assert_ne!(unit_hash, struct_hash);
     assert_ne!(struct_hash, tuple_hash);
 }
+
+#[test]
+#[allow(dead_code)]
+fn variant_only_1() {
+    #[derive(Educe)]
+    #[educe(Hash(variant_only))]
+    enum Enum {
+        Struct { f1: u8 },
+        Tuple(u8),
+    }
+
+    let hash = |e: &Enum| {
+        let mut hasher = DefaultHasher::new();
+
+        e.hash(&mut hasher);
+
+        hasher.finish()
+    };
+
+    assert_eq!(
+        hash(&Enum::Struct {
+            f1: 1
+        }),
+        hash(&Enum::Struct {
+            f1: 2
+        })
+    );
+
+    assert_eq!(hash(&Enum::Tuple(1)), hash(&Enum::Tuple(2)));
+    assert_ne!(hash(&Enum::Tuple(1)), hash(&Enum::Struct {
+        f1: 1
+    }));
+}
+
+#[test]
+fn variant_key_1() {
+    #[derive(Educe)]
+    #[educe(Hash(variant_key = "name"))]
+    enum Enum1 {
+        Unit,
+        Tuple(u8),
+    }
+
+    #[derive(Educe)]
+    #[educe(Hash(variant_key = "name"))]
+    enum Enum2 {
+        Inserted,
+        Tuple(u8),
+        Unit,
+    }
+
+    let hash = |e: &dyn Fn(&mut DefaultHasher)| {
+        let mut hasher = DefaultHasher::new();
+
+        e(&mut hasher);
+
+        hasher.finish()
+    };
+
+    assert_eq!(hash(&|h| Enum1::Unit.hash(h)), hash(&|h| Enum2::Unit.hash(h)));
+    assert_eq!(hash(&|h| Enum1::Tuple(1).hash(h)), hash(&|h| Enum2::Tuple(1).hash(h)));
+    assert_ne!(hash(&|h| Enum1::Tuple(1).hash(h)), hash(&|h| Enum2::Tuple(2).hash(h)));
+    assert_ne!(hash(&|h| Enum2::Inserted.hash(h)), hash(&|h| Enum2::Unit.hash(h)));
+    assert_eq!(hash(&|h| Enum1::Unit.hash(h)), hash(&|h| "Unit".hash(h)));
+}
+
+#[test]
+fn variant_key_2() {
+    #[derive(Educe)]
+    #[educe(Hash(variant_key = "discriminant"))]
+    enum Enum1 {
+        A = 1,
+        B = 2,
+    }
+
+    #[derive(Educe)]
+    #[educe(Hash(variant_key = "discriminant"))]
+    enum Enum2 {
+        B = 2,
+        C = 3,
+        A = 1,
+    }
+
+    #[derive(Educe)]
+    #[educe(Hash(variant_key = "discriminant"))]
+    enum Enum4 {
+        A,
+        B = 5,
+        C,
+    }
+
+    #[derive(Educe)]
+    #[educe(Hash(variant_key = "discriminant"))]
+    #[repr(u8)]
+    enum Enum3 {
+        B(u8) = 2,
+        A(u8) = 1,
+    }
+
+    let hash = |e: &dyn Fn(&mut DefaultHasher)| {
+        let mut hasher = DefaultHasher::new();
+
+        e(&mut hasher);
+
+        hasher.finish()
+    };
+
+    assert_eq!(hash(&|h| Enum1::A.hash(h)), hash(&|h| Enum2::A.hash(h)));
+    assert_eq!(hash(&|h| Enum1::B.hash(h)), hash(&|h| Enum2::B.hash(h)));
+    assert_ne!(hash(&|h| Enum2::C.hash(h)), hash(&|h| Enum2::B.hash(h)));
+    assert_eq!(hash(&|h| Enum3::A(1).hash(h)), hash(&|h| Enum3::A(1).hash(h)));
+    assert_ne!(hash(&|h| Enum3::A(1).hash(h)), hash(&|h| Enum3::B(1).hash(h)));
+    assert_eq!(hash(&|h| Enum1::A.hash(h)), hash(&|h| 1isize.hash(h)));
+    assert_eq!(hash(&|h| Enum4::A.hash(h)), hash(&|h| 0isize.hash(h)));
+    assert_eq!(hash(&|h| Enum4::B.hash(h)), hash(&|h| 5isize.hash(h)));
+    assert_eq!(hash(&|h| Enum4::C.hash(h)), hash(&|h| 6isize.hash(h)));
+}
+
+#[test]
+fn variant_key_3() {
+    #[derive(Educe)]
+    #[educe(Hash(variant_key = "name"))]
+    enum Enum1 {
+        Old,
+        Tuple(u8),
+    }
+
+    #[derive(Educe)]
+    #[educe(Hash(variant_key = "name"))]
+    enum Enum2 {
+        Tuple(u8),
+        #[educe(Hash(variant_key = "Old"))]
+        New,
+    }
+
+    #[derive(Educe)]
+    #[educe(Hash(variant_key = "discriminant"))]
+    enum Enum3 {
+        #[educe(Hash(variant_key = 1))]
+        Renamed = 5,
+    }
+
+    let hash = |e: &dyn Fn(&mut DefaultHasher)| {
+        let mut hasher = DefaultHasher::new();
+
+        e(&mut hasher);
+
+        hasher.finish()
+    };
+
+    assert_eq!(hash(&|h| Enum1::Old.hash(h)), hash(&|h| Enum2::New.hash(h)));
+    assert_eq!(hash(&|h| Enum1::Tuple(1).hash(h)), hash(&|h| Enum2::Tuple(1).hash(h)));
+    assert_eq!(hash(&|h| Enum3::Renamed.hash(h)), hash(&|h| 1isize.hash(h)));
+}
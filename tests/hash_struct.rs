@@ -653,3 +653,199 @@ fn bound_3() {
 
     assert_eq!(struct_hash, tuple_hash);
 }
+
+#[test]
+fn total_1() {
+    #[derive(Educe)]
+    #[educe(Hash)]
+    struct Struct {
+        #[educe(Hash(total))]
+        f1: f64,
+    }
+
+    #[derive(Educe)]
+    #[educe(Hash)]
+    enum Enum {
+        Tuple(#[educe(Hash(total))] f32),
+    }
+
+    let hash = |s: &Struct| {
+        let mut hasher = DefaultHasher::new();
+
+        s.hash(&mut hasher);
+
+        hasher.finish()
+    };
+
+    let enum_hash = |e: &Enum| {
+        let mut hasher = DefaultHasher::new();
+
+        e.hash(&mut hasher);
+
+        hasher.finish()
+    };
+
+    assert_eq!(
+        hash(&Struct {
+            f1: f64::NAN
+        }),
+        hash(&Struct {
+            f1: -f64::NAN
+        })
+    );
+
+    assert_eq!(
+        hash(&Struct {
+            f1: 0.0
+        }),
+        hash(&Struct {
+            f1: -0.0
+        })
+    );
+
+    assert_eq!(enum_hash(&Enum::Tuple(0.0)), enum_hash(&Enum::Tuple(-0.0)));
+    assert_ne!(enum_hash(&Enum::Tuple(1.0)), enum_hash(&Enum::Tuple(0.0)));
+}
+
+#[test]
+fn by_1() {
+    #[derive(Educe)]
+    #[educe(Hash)]
+    struct Struct {
+        #[educe(Hash(by = "|s: &String| s.to_lowercase()"))]
+        f1: String,
+    }
+
+    let hash = |s: &Struct| {
+        let mut hasher = DefaultHasher::new();
+
+        s.hash(&mut hasher);
+
+        hasher.finish()
+    };
+
+    assert_eq!(
+        hash(&Struct {
+            f1: String::from("Hello")
+        }),
+        hash(&Struct {
+            f1: String::from("hELLO")
+        })
+    );
+
+    assert_ne!(
+        hash(&Struct {
+            f1: String::from("Hello")
+        }),
+        hash(&Struct {
+            f1: String::from("World")
+        })
+    );
+}
+
+#[test]
+fn ptr_1() {
+    use std::rc::Rc;
+
+    #[derive(Educe)]
+    #[educe(Hash)]
+    struct Struct {
+        #[educe(Hash(ptr))]
+        f1: Rc<String>,
+    }
+
+    let hash = |s: &Struct| {
+        let mut hasher = DefaultHasher::new();
+
+        s.hash(&mut hasher);
+
+        hasher.finish()
+    };
+
+    let rc = Rc::new(String::from("Hello"));
+
+    assert_eq!(
+        hash(&Struct {
+            f1: rc.clone()
+        }),
+        hash(&Struct {
+            f1: rc.clone()
+        })
+    );
+
+    let address = {
+        let mut hasher = DefaultHasher::new();
+
+        (Rc::as_ptr(&rc) as *const () as usize).hash(&mut hasher);
+
+        hasher.finish()
+    };
+
+    assert_eq!(
+        address,
+        hash(&Struct {
+            f1: rc
+        })
+    );
+}
+
+#[test]
+fn unordered_1() {
+    use std::collections::{HashMap, HashSet};
+
+    #[derive(Educe)]
+    #[educe(Hash)]
+    struct Struct {
+        #[educe(Hash(unordered))]
+        f1: Vec<u8>,
+        #[educe(Hash(unordered))]
+        f2: HashSet<u8>,
+        #[educe(Hash(unordered))]
+        f3: HashMap<u8, u8>,
+    }
+
+    let hash = |s: &Struct| {
+        let mut hasher = DefaultHasher::new();
+
+        s.hash(&mut hasher);
+
+        hasher.finish()
+    };
+
+    let a = Struct {
+        f1: vec![1, 2, 2],
+        f2: (0..100).collect(),
+        f3: (0..100).map(|i| (i, i)).collect(),
+    };
+
+    let b = Struct {
+        f1: vec![2, 2, 1],
+        f2: (0..100).rev().collect(),
+        f3: (0..100).rev().map(|i| (i, i)).collect(),
+    };
+
+    let c = Struct {
+        f1: vec![1, 1, 2],
+        f2: (0..100).collect(),
+        f3: (0..100).map(|i| (i, i)).collect(),
+    };
+
+    assert_eq!(hash(&a), hash(&b));
+    assert_ne!(hash(&a), hash(&c));
+}
+
+#[test]
+fn unordered_2() {
+    use std::collections::HashSet;
+
+    #[derive(Educe)]
+    #[educe(PartialEq, Eq, Hash)]
+    struct Bag(#[educe(PartialEq(unordered), Hash(unordered))] Vec<u8>);
+
+    let mut set = HashSet::new();
+
+    set.insert(Bag(vec![1, 2, 3]));
+
+    assert!(set.contains(&Bag(vec![3, 2, 1])));
+    assert!(!set.contains(&Bag(vec![3, 3, 1])));
+}
@@ -0,0 +1,103 @@
+#![cfg(feature = "Hash")]
+
+#[macro_use]
+extern crate educe;
+
+use core::hash::{Hash, Hasher};
+
+use std::collections::hash_map::DefaultHasher;
+
+#[test]
+fn union_tag_1() {
+    #[derive(Hash, Clone, Copy)]
+    #[repr(C)]
+    struct Small {
+        tag: u8,
+        value: u8,
+    }
+
+    #[derive(Hash, Clone, Copy)]
+    #[repr(C)]
+    struct Large {
+        tag: u8,
+        value: u32,
+    }
+
+    #[derive(Educe)]
+    #[educe(Hash, union_tag("Self::active_field"))]
+    union Union {
+        small: Small,
+        large: Large,
+    }
+
+    impl Union {
+        fn active_field(&self) -> &'static str {
+            match unsafe { self.small.tag } {
+                0 => "small",
+                _ => "large",
+            }
+        }
+    }
+
+    let union_hash = {
+        let mut hasher = DefaultHasher::new();
+
+        Union {
+            large: Large {
+                tag: 1,
+                value: 2,
+            },
+        }
+        .hash(&mut hasher);
+
+        hasher.finish()
+    };
+
+    let field_hash = {
+        let mut hasher = DefaultHasher::new();
+
+        "large".hash(&mut hasher);
+
+        Large {
+            tag: 1,
+            value: 2,
+        }
+        .hash(&mut hasher);
+
+        hasher.finish()
+    };
+
+    assert_eq!(field_hash, union_hash);
+}
+
+#[test]
+#[allow(dead_code)]
+fn bytes_1() {
+    #[derive(Educe)]
+    #[educe(Hash(bytes))]
+    union Union {
+        f1: [u8; 2],
+        f2: u16,
+    }
+
+    let union_hash = {
+        let mut hasher = DefaultHasher::new();
+
+        Union {
+            f1: [1, 2]
+        }
+        .hash(&mut hasher);
+
+        hasher.finish()
+    };
+
+    let bytes_hash = {
+        let mut hasher = DefaultHasher::new();
+
+        [1u8, 2].as_ref().hash(&mut hasher);
+
+        hasher.finish()
+    };
+
+    assert_eq!(bytes_hash, union_hash);
+}
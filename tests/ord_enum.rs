@@ -859,3 +859,231 @@ fn variant_rank_2() {
 
     assert_eq!(Ordering::Greater, Enum::Two.cmp(&Enum::One));
 }
+
+#[test]
+fn reverse_1() {
+    #[derive(Educe)]
+    #[educe(PartialEq, Eq, PartialOrd, Ord)]
+    enum Enum {
+        Struct {
+            f1: u8,
+            #[educe(Ord(reverse))]
+            f2: u8,
+        },
+        #[educe(Ord(reverse))]
+        Tuple(u8, #[educe(Ord(reverse))] u8),
+    }
+
+    assert_eq!(
+        Ordering::Greater,
+        Enum::Struct {
+            f1: 1,
+            f2: 1
+        }
+        .cmp(&Enum::Struct {
+            f1: 1,
+            f2: 2
+        })
+    );
+
+    assert_eq!(Ordering::Greater, Enum::Tuple(1, 1).cmp(&Enum::Tuple(2, 1)));
+    assert_eq!(Ordering::Less, Enum::Tuple(1, 1).cmp(&Enum::Tuple(1, 2)));
+
+    assert_eq!(
+        Ordering::Less,
+        Enum::Struct {
+            f1: 2,
+            f2: 2
+        }
+        .cmp(&Enum::Tuple(1, 1))
+    );
+}
+
+#[test]
+fn reverse_2() {
+    #[derive(Educe)]
+    #[educe(PartialEq, Eq, PartialOrd, Ord(reverse))]
+    enum Enum {
+        #[educe(Ord(rank = 1))]
+        One,
+        #[educe(Ord(rank = 2))]
+        Two(u8),
+    }
+
+    assert_eq!(Ordering::Greater, Enum::One.cmp(&Enum::Two(0)));
+    assert_eq!(Ordering::Less, Enum::Two(2).cmp(&Enum::Two(1)));
+
+    #[derive(Educe)]
+    #[educe(PartialEq, Eq, PartialOrd, Ord(reverse))]
+    enum Unit {
+        A,
+        B,
+    }
+
+    assert_eq!(Ordering::Greater, Unit::A.cmp(&Unit::B));
+}
+
+#[test]
+fn none_1() {
+    #[derive(Educe)]
+    #[educe(PartialEq, Eq, PartialOrd, Ord)]
+    enum Enum {
+        Struct {
+            #[educe(Ord(none = "last"))]
+            f1: Option<u8>,
+        },
+        Tuple(#[educe(Ord(none = "last"))] Option<u8>),
+    }
+
+    assert_eq!(
+        Ordering::Greater,
+        Enum::Struct {
+            f1: None
+        }
+        .cmp(&Enum::Struct {
+            f1: Some(1)
+        })
+    );
+
+    assert_eq!(
+        Ordering::Less,
+        Enum::Struct {
+            f1: Some(1)
+        }
+        .cmp(&Enum::Struct {
+            f1: Some(2)
+        })
+    );
+
+    assert_eq!(Ordering::Greater, Enum::Tuple(None).cmp(&Enum::Tuple(Some(1))));
+    assert_eq!(Ordering::Less, Enum::Tuple(Some(1)).cmp(&Enum::Tuple(None)));
+    assert_eq!(Ordering::Equal, Enum::Tuple(None).cmp(&Enum::Tuple(None)));
+}
+
+#[test]
+fn total_1() {
+    #[derive(Educe)]
+    #[educe(PartialEq, Eq, PartialOrd, Ord)]
+    enum Enum {
+        Struct {
+            #[educe(PartialEq(total), PartialOrd(total), Ord(total))]
+            f1: f64,
+        },
+        Tuple(#[educe(PartialEq(total), PartialOrd(total), Ord(total))] f32),
+    }
+
+    assert_eq!(
+        Ordering::Greater,
+        Enum::Struct {
+            f1: f64::NAN
+        }
+        .cmp(&Enum::Struct {
+            f1: 1.0
+        })
+    );
+
+    assert_eq!(Ordering::Equal, Enum::Tuple(-0.0).cmp(&Enum::Tuple(0.0)));
+    assert_eq!(Ordering::Less, Enum::Tuple(-1.0).cmp(&Enum::Tuple(-0.0)));
+}
+
+#[test]
+#[allow(dead_code)]
+fn variant_only_1() {
+    #[derive(Educe)]
+    #[educe(PartialEq(variant_only), Eq, PartialOrd(variant_only), Ord(variant_only))]
+    enum Enum {
+        Struct {
+            f1: u8,
+        },
+        #[educe(PartialOrd(rank = 0), Ord(rank = 0))]
+        Tuple(u8),
+    }
+
+    assert_eq!(
+        Ordering::Equal,
+        Enum::Struct {
+            f1: 1
+        }
+        .cmp(&Enum::Struct {
+            f1: 2
+        })
+    );
+
+    assert_eq!(
+        Ordering::Less,
+        Enum::Struct {
+            f1: 1
+        }
+        .cmp(&Enum::Tuple(2))
+    );
+
+    assert_eq!(Some(Ordering::Equal), Enum::Tuple(2).partial_cmp(&Enum::Tuple(1)));
+}
+
+#[test]
+#[allow(dead_code)]
+fn via_ord_1() {
+    #[derive(Educe)]
+    #[educe(PartialEq, Eq, PartialOrd(via_ord), Ord)]
+    enum Enum {
+        Struct { f1: u8 },
+        #[educe(Ord(rank = 0))]
+        Tuple(u8),
+    }
+
+    assert_eq!(Some(Ordering::Less), Enum::Struct { f1: 2 }.partial_cmp(&Enum::Tuple(1)));
+    assert!(Enum::Tuple(1) > Enum::Struct { f1: 2 });
+}
+
+#[test]
+#[allow(dead_code)]
+fn variant_rank_3() {
+    const PRIORITY_HIGH: u8 = 2;
+    const PRIORITY_LOW: u8 = 1;
+
+    #[derive(Educe)]
+    #[educe(PartialEq, Eq, PartialOrd, Ord)]
+    enum Enum {
+        #[educe(Ord(rank = "PRIORITY_HIGH"))]
+        High { f1: u8 },
+        #[educe(Ord(rank = "PRIORITY_LOW"))]
+        Low(u8),
+        #[educe(Ord(rank("PRIORITY_HIGH + 1")))]
+        Urgent,
+    }
+
+    assert_eq!(Ordering::Greater, Enum::High { f1: 1 }.cmp(&Enum::Low(2)));
+    assert_eq!(Ordering::Less, Enum::High { f1: 1 }.cmp(&Enum::Urgent));
+    assert_eq!(Ordering::Less, Enum::Low(1).cmp(&Enum::Urgent));
+}
+
+#[test]
+fn variant_rank_4() {
+    #[derive(Educe)]
+    #[educe(PartialEq, Eq, PartialOrd, Ord)]
+    enum Unit {
+        #[educe(Ord(rank = "discriminant"))]
+        A = 3,
+        #[educe(Ord(rank = "discriminant"))]
+        B = 1,
+        #[educe(Ord(rank = "discriminant"))]
+        C,
+    }
+
+    #[derive(Educe)]
+    #[educe(PartialEq, Eq, PartialOrd, Ord)]
+    #[repr(u8)]
+    enum Enum {
+        #[educe(Ord(rank = "discriminant"))]
+        A(u8) = 2,
+        #[educe(Ord(rank = "discriminant"))]
+        B(u8) = 1,
+    }
+
+    assert_eq!(Ordering::Greater, Unit::A.cmp(&Unit::B));
+    assert_eq!(Ordering::Greater, Unit::A.cmp(&Unit::C));
+    assert_eq!(Ordering::Less, Unit::B.cmp(&Unit::C));
+
+    assert_eq!(Ordering::Greater, Enum::A(1).cmp(&Enum::B(2)));
+    assert_eq!(Ordering::Less, Enum::A(1).cmp(&Enum::A(2)));
+}
@@ -837,3 +837,347 @@ fn rank_4() {
     assert_eq!(Ordering::Less, Tuple(2, 1).cmp(&Tuple(1, 2)));
     assert_eq!(Ordering::Greater, Tuple(1, 2).cmp(&Tuple(2, 1)));
 }
+
+#[test]
+fn reverse_1() {
+    #[derive(Educe)]
+    #[educe(PartialEq, Eq, PartialOrd, Ord)]
+    struct Struct {
+        f1: u8,
+        #[educe(Ord(reverse))]
+        f2: u8,
+    }
+
+    #[derive(Educe)]
+    #[educe(PartialEq, Eq, PartialOrd, Ord)]
+    struct Tuple(#[educe(Ord(rank = 1))] u8, #[educe(Ord(reverse))] u8);
+
+    assert_eq!(
+        Ordering::Greater,
+        Struct {
+            f1: 1,
+            f2: 1
+        }
+        .cmp(&Struct {
+            f1: 1,
+            f2: 2
+        })
+    );
+
+    assert_eq!(
+        Ordering::Less,
+        Struct {
+            f1: 1,
+            f2: 2
+        }
+        .cmp(&Struct {
+            f1: 2,
+            f2: 1
+        })
+    );
+
+    assert_eq!(Ordering::Greater, Tuple(2, 1).cmp(&Tuple(1, 2)));
+    assert_eq!(Ordering::Less, Tuple(1, 2).cmp(&Tuple(2, 2)));
+}
+
+#[test]
+fn reverse_2() {
+    #[derive(Educe)]
+    #[educe(PartialEq, Eq, PartialOrd, Ord(reverse))]
+    struct Struct {
+        f1: u8,
+        #[educe(Ord(reverse))]
+        f2: u8,
+    }
+
+    assert_eq!(
+        Ordering::Less,
+        Struct {
+            f1: 2,
+            f2: 1
+        }
+        .cmp(&Struct {
+            f1: 1,
+            f2: 2
+        })
+    );
+
+    assert_eq!(
+        Ordering::Less,
+        Struct {
+            f1: 1,
+            f2: 1
+        }
+        .cmp(&Struct {
+            f1: 1,
+            f2: 2
+        })
+    );
+}
+
+#[test]
+fn none_1() {
+    #[derive(Educe)]
+    #[educe(PartialEq, Eq, PartialOrd, Ord)]
+    struct Struct {
+        #[educe(Ord(none = "last"))]
+        f1: Option<u8>,
+        f2: u8,
+    }
+
+    #[derive(Educe)]
+    #[educe(PartialEq, Eq, PartialOrd, Ord)]
+    struct Tuple(#[educe(Ord(none("last"), reverse))] Option<u8>);
+
+    assert_eq!(
+        Ordering::Greater,
+        Struct {
+            f1: None,
+            f2: 0
+        }
+        .cmp(&Struct {
+            f1: Some(1),
+            f2: 1
+        })
+    );
+
+    assert_eq!(
+        Ordering::Less,
+        Struct {
+            f1: Some(1),
+            f2: 1
+        }
+        .cmp(&Struct {
+            f1: Some(2),
+            f2: 0
+        })
+    );
+
+    assert_eq!(
+        Ordering::Less,
+        Struct {
+            f1: None,
+            f2: 0
+        }
+        .cmp(&Struct {
+            f1: None,
+            f2: 1
+        })
+    );
+
+    assert_eq!(Ordering::Less, Tuple(Some(2)).cmp(&Tuple(Some(1))));
+    assert_eq!(Ordering::Less, Tuple(Some(1)).cmp(&Tuple(None)));
+    assert_eq!(Ordering::Equal, Tuple(None).cmp(&Tuple(None)));
+}
+
+#[test]
+fn none_2() {
+    fn cmp_reversed(a: &u8, b: &u8) -> Ordering {
+        b.cmp(a)
+    }
+
+    #[derive(Educe)]
+    #[educe(PartialEq, Eq, PartialOrd, Ord)]
+    struct Struct {
+        #[educe(Ord(none = "first", method = "cmp_reversed"))]
+        f1: Option<u8>,
+    }
+
+    assert_eq!(
+        Ordering::Less,
+        Struct {
+            f1: None
+        }
+        .cmp(&Struct {
+            f1: Some(1)
+        })
+    );
+
+    assert_eq!(
+        Ordering::Greater,
+        Struct {
+            f1: Some(1)
+        }
+        .cmp(&Struct {
+            f1: Some(2)
+        })
+    );
+}
+
+#[test]
+fn total_1() {
+    #[derive(Educe)]
+    #[educe(PartialEq, Eq, PartialOrd, Ord)]
+    struct Struct {
+        #[educe(PartialEq(total), PartialOrd(total), Ord(total))]
+        f1: f64,
+    }
+
+    assert_eq!(
+        Ordering::Greater,
+        Struct {
+            f1: f64::NAN
+        }
+        .cmp(&Struct {
+            f1: f64::INFINITY
+        })
+    );
+
+    assert_eq!(
+        Ordering::Equal,
+        Struct {
+            f1: -f64::NAN
+        }
+        .cmp(&Struct {
+            f1: f64::NAN
+        })
+    );
+
+    assert_eq!(
+        Ordering::Equal,
+        Struct {
+            f1: -0.0
+        }
+        .cmp(&Struct {
+            f1: 0.0
+        })
+    );
+
+    assert_eq!(
+        Some(Ordering::Less),
+        Struct {
+            f1: 1.0
+        }
+        .partial_cmp(&Struct {
+            f1: f64::NAN
+        })
+    );
+}
+
+#[test]
+fn by_1() {
+    fn key(s: &&str) -> usize {
+        s.len()
+    }
+
+    #[derive(Educe)]
+    #[educe(PartialEq, Eq, PartialOrd, Ord)]
+    struct Struct {
+        #[educe(
+            PartialEq(by = "key"),
+            PartialOrd(by = "key", reverse),
+            Ord(by = "key", reverse)
+        )]
+        f1: &'static str,
+    }
+
+    #[derive(Educe)]
+    #[educe(PartialEq, Eq, PartialOrd, Ord)]
+    enum Enum {
+        Tuple(#[educe(PartialEq(by = "key"), PartialOrd(by = "key"), Ord(by = "key"))] &'static str),
+    }
+
+    assert_eq!(
+        Ordering::Less,
+        Struct {
+            f1: "abc"
+        }
+        .cmp(&Struct {
+            f1: "ab"
+        })
+    );
+
+    assert_eq!(
+        Ordering::Equal,
+        Struct {
+            f1: "abc"
+        }
+        .cmp(&Struct {
+            f1: "xyz"
+        })
+    );
+
+    assert!(
+        Struct {
+            f1: "abc"
+        } == Struct {
+            f1: "xyz"
+        }
+    );
+
+    assert_eq!(Ordering::Greater, Enum::Tuple("abc").cmp(&Enum::Tuple("ab")));
+    assert_eq!(Some(Ordering::Equal), Enum::Tuple("ab").partial_cmp(&Enum::Tuple("xy")));
+}
+
+#[test]
+fn ptr_1() {
+    #[derive(Educe)]
+    #[educe(PartialEq, Eq, PartialOrd, Ord)]
+    struct Struct<'a> {
+        #[educe(PartialEq(ptr), PartialOrd(ptr), Ord(ptr))]
+        f1: &'a u8,
+    }
+
+    let values = [2u8, 1u8];
+
+    assert_eq!(
+        Ordering::Less,
+        Struct {
+            f1: &values[0]
+        }
+        .cmp(&Struct {
+            f1: &values[1]
+        })
+    );
+
+    assert_eq!(
+        Ordering::Equal,
+        Struct {
+            f1: &values[1]
+        }
+        .cmp(&Struct {
+            f1: &values[1]
+        })
+    );
+}
+
+#[test]
+fn via_ord_1() {
+    #[derive(Educe)]
+    #[educe(PartialEq, Eq, PartialOrd(via_ord), Ord)]
+    struct Struct {
+        #[educe(Ord(rank = 1))]
+        f1: u8,
+        #[educe(Ord(rank = 0, reverse))]
+        f2: u8,
+    }
+
+    #[derive(Educe)]
+    #[educe(PartialEq(bound), Eq(bound), PartialOrd(via_ord), Ord(bound))]
+    struct Tuple<T>(#[educe(Ord(method = "cmp_rev"))] T);
+
+    fn cmp_rev<T: Ord>(a: &T, b: &T) -> Ordering {
+        b.cmp(a)
+    }
+
+    assert_eq!(
+        Some(Ordering::Less),
+        Struct {
+            f1: 1, f2: 3
+        }
+        .partial_cmp(&Struct {
+            f1: 2, f2: 2
+        })
+    );
+
+    assert!(
+        Struct {
+            f1: 2, f2: 3
+        } < Struct {
+            f1: 1, f2: 2
+        }
+    );
+
+    assert_eq!(Some(Ordering::Greater), Tuple(1).partial_cmp(&Tuple(2)));
+    assert!(Tuple(2) < Tuple(1));
+}
@@ -0,0 +1,37 @@
+#![cfg(all(feature = "PartialEq", feature = "Eq", feature = "PartialOrd", feature = "Ord"))]
+#![no_std]
+
+#[macro_use]
+extern crate educe;
+
+use core::cmp::Ordering;
+
+#[test]
+#[allow(dead_code)]
+fn bytes_1() {
+    #[derive(Educe)]
+    #[educe(PartialEq(bytes), Eq, PartialOrd(bytes), Ord(bytes))]
+    union Union {
+        f1: [u8; 2],
+        f2: u16,
+    }
+
+    assert_eq!(
+        Ordering::Less,
+        Union {
+            f1: [1, 2]
+        }
+        .cmp(&Union {
+            f1: [1, 3]
+        })
+    );
+    assert_eq!(
+        Ordering::Equal,
+        Union {
+            f1: [1, 2]
+        }
+        .cmp(&Union {
+            f1: [1, 2]
+        })
+    );
+}
@@ -527,3 +527,122 @@ fn bound_3() {
     assert!(Enum::Tuple(1) == Enum::Tuple(1));
     assert!(Enum::Tuple(1) != Enum::Tuple(2));
 }
+
+#[test]
+fn total_1() {
+    #[derive(Educe)]
+    #[educe(PartialEq)]
+    enum Enum {
+        Struct {
+            #[educe(PartialEq(total))]
+            f1: f64,
+        },
+        Tuple(#[educe(PartialEq(total))] f32),
+    }
+
+    assert!(
+        Enum::Struct {
+            f1: f64::NAN
+        } == Enum::Struct {
+            f1: f64::NAN
+        }
+    );
+
+    assert!(Enum::Tuple(0.0) == Enum::Tuple(-0.0));
+    assert!(Enum::Tuple(1.0) != Enum::Tuple(f32::NAN));
+}
+
+#[test]
+fn approx_1() {
+    #[derive(Educe)]
+    #[educe(PartialEq)]
+    enum Enum {
+        Struct {
+            #[educe(PartialEq(approx = 1e-9))]
+            f1: f64,
+        },
+        Tuple(#[educe(PartialEq(approx(relative = 0.1)))] f32),
+    }
+
+    assert!(
+        Enum::Struct {
+            f1: 0.1 + 0.2
+        } == Enum::Struct {
+            f1: 0.3
+        }
+    );
+
+    assert!(Enum::Tuple(10.0) == Enum::Tuple(11.0));
+    assert!(Enum::Tuple(10.0) != Enum::Tuple(12.0));
+}
+
+#[test]
+fn by_1() {
+    #[derive(Educe)]
+    #[educe(PartialEq)]
+    enum Enum {
+        Struct {
+            #[educe(PartialEq(by = "|v: &u8| v % 10"))]
+            f1: u8,
+        },
+        Tuple(#[educe(PartialEq(by = "|v: &u8| v % 10"))] u8),
+    }
+
+    assert!(
+        Enum::Struct {
+            f1: 12
+        } == Enum::Struct {
+            f1: 2
+        }
+    );
+
+    assert!(Enum::Tuple(15) == Enum::Tuple(5));
+    assert!(Enum::Tuple(15) != Enum::Tuple(6));
+}
+
+#[test]
+fn unordered_1() {
+    #[derive(Educe)]
+    #[educe(PartialEq)]
+    enum Enum {
+        Struct {
+            #[educe(PartialEq(unordered))]
+            f1: [u8; 2],
+        },
+        Tuple(#[educe(PartialEq(unordered))] [u8; 2]),
+    }
+
+    assert!(
+        Enum::Struct {
+            f1: [1, 2]
+        } == Enum::Struct {
+            f1: [2, 1]
+        }
+    );
+
+    assert!(Enum::Tuple([1, 2]) == Enum::Tuple([2, 1]));
+    assert!(Enum::Tuple([1, 2]) != Enum::Tuple([1, 1]));
+}
+
+#[test]
+#[allow(dead_code)]
+fn variant_only_1() {
+    #[derive(Educe)]
+    #[educe(PartialEq(variant_only))]
+    enum Enum {
+        Unit,
+        Struct { f1: u8 },
+        Tuple(u8, u8),
+    }
+
+    assert!(Enum::Unit == Enum::Unit);
+    assert!(
+        Enum::Struct {
+            f1: 1
+        } == Enum::Struct {
+            f1: 2
+        }
+    );
+    assert!(Enum::Tuple(1, 2) == Enum::Tuple(3, 4));
+    assert!(Enum::Tuple(1, 2) != Enum::Unit);
+}
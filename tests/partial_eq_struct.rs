@@ -530,3 +530,359 @@ fn bound_3() {
     assert!(Tuple(1) == Tuple(1));
     assert!(Tuple(1) != Tuple(2));
 }
+
+#[test]
+fn total_1() {
+    #[derive(Educe)]
+    #[educe(PartialEq)]
+    struct Struct {
+        #[educe(PartialEq(total))]
+        f1: f64,
+    }
+
+    #[derive(Educe)]
+    #[educe(PartialEq)]
+    struct Tuple(#[educe(PartialEq(total))] f32);
+
+    assert!(
+        Struct {
+            f1: f64::NAN
+        } == Struct {
+            f1: -f64::NAN
+        }
+    );
+
+    assert!(
+        Struct {
+            f1: 0.0
+        } == Struct {
+            f1: -0.0
+        }
+    );
+
+    assert!(
+        Struct {
+            f1: 1.0
+        } != Struct {
+            f1: 2.0
+        }
+    );
+
+    assert!(Tuple(f32::NAN) == Tuple(f32::NAN));
+    assert!(Tuple(1.0) != Tuple(f32::NAN));
+}
+
+#[test]
+fn approx_1() {
+    #[derive(Educe)]
+    #[educe(PartialEq)]
+    struct Struct {
+        #[educe(PartialEq(approx = 1e-9))]
+        f1: f64,
+    }
+
+    #[derive(Educe)]
+    #[educe(PartialEq)]
+    struct Tuple(#[educe(PartialEq(approx(0.5)))] f32);
+
+    assert!(
+        Struct {
+            f1: 0.1 + 0.2
+        } == Struct {
+            f1: 0.3
+        }
+    );
+
+    assert!(
+        Struct {
+            f1: 0.3
+        } != Struct {
+            f1: 0.31
+        }
+    );
+
+    assert!(
+        Struct {
+            f1: f64::INFINITY
+        } == Struct {
+            f1: f64::INFINITY
+        }
+    );
+
+    assert!(
+        Struct {
+            f1: f64::NAN
+        } != Struct {
+            f1: f64::NAN
+        }
+    );
+
+    assert!(Tuple(1.0) == Tuple(1.5));
+    assert!(Tuple(1.0) != Tuple(1.6));
+}
+
+#[test]
+fn approx_2() {
+    extern crate alloc;
+
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[derive(Educe)]
+    #[educe(PartialEq)]
+    struct Struct {
+        #[educe(PartialEq(approx(relative = 0.01)))]
+        f1: [f64; 2],
+        #[educe(PartialEq(approx(absolute = 0.1)))]
+        f2: Vec<f32>,
+    }
+
+    assert!(
+        Struct {
+            f1: [100.0, -1.0],
+            f2: vec![1.0],
+        } == Struct {
+            f1: [100.5, -1.005],
+            f2: vec![1.05],
+        }
+    );
+
+    assert!(
+        Struct {
+            f1: [100.0, -1.0],
+            f2: vec![1.0],
+        } != Struct {
+            f1: [102.0, -1.0],
+            f2: vec![1.0],
+        }
+    );
+
+    assert!(
+        Struct {
+            f1: [100.0, -1.0],
+            f2: vec![1.0],
+        } != Struct {
+            f1: [100.0, -1.0],
+            f2: vec![1.0, 2.0],
+        }
+    );
+}
+
+#[test]
+fn by_1() {
+    struct Item {
+        id: u8,
+        _weight: u8,
+    }
+
+    #[derive(Educe)]
+    #[educe(PartialEq)]
+    struct Struct {
+        #[educe(PartialEq(by = "|v: &Item| v.id"))]
+        f1: Item,
+    }
+
+    #[derive(Educe)]
+    #[educe(PartialEq)]
+    struct Tuple(#[educe(PartialEq(by("|v: &u8| v.is_power_of_two()")))] u8);
+
+    assert!(
+        Struct {
+            f1: Item {
+                id: 1,
+                _weight: 1
+            }
+        } == Struct {
+            f1: Item {
+                id: 1,
+                _weight: 2
+            }
+        }
+    );
+
+    assert!(
+        Struct {
+            f1: Item {
+                id: 1,
+                _weight: 1
+            }
+        } != Struct {
+            f1: Item {
+                id: 2,
+                _weight: 1
+            }
+        }
+    );
+
+    assert!(Tuple(2) == Tuple(8));
+    assert!(Tuple(2) != Tuple(6));
+}
+
+#[test]
+fn ptr_1() {
+    extern crate alloc;
+
+    use alloc::rc::Rc;
+
+    #[derive(Educe)]
+    #[educe(PartialEq)]
+    struct Struct<'a> {
+        #[educe(PartialEq(ptr))]
+        f1: Rc<u8>,
+        #[educe(PartialEq(ptr))]
+        f2: &'a str,
+        #[educe(PartialEq(ptr))]
+        f3: *const u8,
+    }
+
+    let rc = Rc::new(1);
+    let s = "hello";
+    let n = 1u8;
+
+    assert!(
+        Struct {
+            f1: rc.clone(),
+            f2: s,
+            f3: &n,
+        } == Struct {
+            f1: rc.clone(),
+            f2: s,
+            f3: &n,
+        }
+    );
+
+    assert!(
+        Struct {
+            f1: rc.clone(),
+            f2: s,
+            f3: &n,
+        } != Struct {
+            f1: Rc::new(1),
+            f2: s,
+            f3: &n,
+        }
+    );
+
+    assert!(
+        Struct {
+            f1: rc.clone(),
+            f2: &s[..1],
+            f3: &n,
+        } == Struct {
+            f1: rc,
+            f2: &s[..2],
+            f3: &n,
+        }
+    );
+}
+
+#[test]
+fn unordered_1() {
+    extern crate alloc;
+
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[derive(Educe)]
+    #[educe(PartialEq)]
+    struct Struct {
+        #[educe(PartialEq(unordered))]
+        f1: Vec<u8>,
+    }
+
+    #[derive(Educe)]
+    #[educe(PartialEq)]
+    struct Tuple(#[educe(PartialEq(unordered))] [u8; 3]);
+
+    assert!(
+        Struct {
+            f1: vec![1, 2, 2]
+        } == Struct {
+            f1: vec![2, 1, 2]
+        }
+    );
+
+    assert!(
+        Struct {
+            f1: vec![1, 2, 2]
+        } != Struct {
+            f1: vec![1, 1, 2]
+        }
+    );
+
+    assert!(
+        Struct {
+            f1: vec![1, 2]
+        } != Struct {
+            f1: vec![1, 2, 2]
+        }
+    );
+
+    assert!(Tuple([1, 2, 3]) == Tuple([3, 2, 1]));
+    assert!(Tuple([1, 2, 3]) != Tuple([3, 2, 2]));
+}
+
+#[test]
+fn rhs_1() {
+    extern crate alloc;
+
+    use alloc::string::String;
+
+    #[derive(Educe)]
+    #[educe(PartialEq(rhs = "&str", rhs = "String"))]
+    struct Name<'a>(&'a str);
+
+    #[derive(Educe)]
+    #[educe(PartialEq(rhs("u16")))]
+    struct Version(u16);
+
+    assert!(Name("educe") == "educe");
+    assert!(Name("educe") != "derive");
+    let name = String::from("educe");
+
+    assert!(Name("educe") == name);
+    assert!(Name("educe") == Name("educe"));
+
+    assert!(Version(1) == 1u16);
+    assert!(Version(1) != 2u16);
+}
+
+#[test]
+#[allow(dead_code)]
+fn rhs_2() {
+    struct Other {
+        f1: u8,
+        f2: u16,
+    }
+
+    #[derive(Educe)]
+    #[educe(PartialEq(rhs = "Other"))]
+    struct Struct {
+        f1: u8,
+        #[educe(PartialEq(ignore))]
+        f2: u8,
+    }
+
+    #[derive(Educe)]
+    #[educe(PartialEq(rhs = "(u8, u8)"))]
+    struct Tuple(u8, u8);
+
+    assert!(
+        Struct {
+            f1: 1, f2: 2
+        } == Other {
+            f1: 1, f2: 3
+        }
+    );
+
+    assert!(
+        Struct {
+            f1: 1, f2: 2
+        } != Other {
+            f1: 2, f2: 2
+        }
+    );
+
+    assert!(Tuple(1, 2) == (1, 2));
+    assert!(Tuple(1, 2) != (1, 3));
+}
@@ -0,0 +1,98 @@
+#![cfg(feature = "PartialEq")]
+#![no_std]
+
+#[macro_use]
+extern crate educe;
+
+#[test]
+fn union_tag_1() {
+    #[derive(PartialEq, Clone, Copy)]
+    #[repr(C)]
+    struct Small {
+        tag: u8,
+        value: u8,
+    }
+
+    #[derive(PartialEq, Clone, Copy)]
+    #[repr(C)]
+    struct Large {
+        tag: u8,
+        value: u32,
+    }
+
+    #[derive(Educe)]
+    #[educe(PartialEq, union_tag = "Self::active_field")]
+    union Union {
+        small: Small,
+        large: Large,
+    }
+
+    impl Union {
+        fn active_field(&self) -> &'static str {
+            match unsafe { self.small.tag } {
+                0 => "small",
+                _ => "large",
+            }
+        }
+    }
+
+    let small = Union {
+        small: Small {
+            tag: 0,
+            value: 1,
+        },
+    };
+    let large = Union {
+        large: Large {
+            tag: 1,
+            value: 1,
+        },
+    };
+
+    assert!(small == Union {
+        small: Small {
+            tag: 0,
+            value: 1,
+        },
+    });
+    assert!(small != Union {
+        small: Small {
+            tag: 0,
+            value: 2,
+        },
+    });
+    assert!(small != large);
+}
+
+#[test]
+#[allow(dead_code)]
+fn bytes_1() {
+    #[derive(Educe)]
+    #[educe(PartialEq(bytes))]
+    union Union {
+        f1: [u8; 4],
+        f2: u32,
+    }
+
+    assert!(
+        Union {
+            f1: [1, 2, 3, 4]
+        } == Union {
+            f1: [1, 2, 3, 4]
+        }
+    );
+    assert!(
+        Union {
+            f1: [1, 2, 3, 4]
+        } != Union {
+            f1: [1, 2, 3, 5]
+        }
+    );
+    assert!(
+        Union {
+            f2: u32::from_ne_bytes([1, 2, 3, 4])
+        } == Union {
+            f1: [1, 2, 3, 4]
+        }
+    );
+}
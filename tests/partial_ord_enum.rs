@@ -823,3 +823,169 @@ fn variant_rank_2() {
 
     assert!(Enum::Two > Enum::One);
 }
+
+#[test]
+fn reverse_1() {
+    #[derive(Educe)]
+    #[educe(PartialEq, PartialOrd)]
+    enum Enum {
+        Struct {
+            f1: u8,
+            #[educe(PartialOrd(reverse))]
+            f2: u8,
+        },
+        #[educe(PartialOrd(reverse))]
+        Tuple(u8, u8),
+    }
+
+    assert!(
+        Enum::Struct {
+            f1: 1,
+            f2: 1
+        } > Enum::Struct {
+            f1: 1,
+            f2: 2
+        }
+    );
+
+    assert!(Enum::Tuple(1, 1) > Enum::Tuple(2, 1));
+    assert!(
+        Enum::Struct {
+            f1: 2,
+            f2: 2
+        } < Enum::Tuple(1, 1)
+    );
+
+    #[derive(Educe)]
+    #[educe(PartialEq, PartialOrd(reverse))]
+    enum Unit {
+        A,
+        B,
+    }
+
+    assert!(Unit::A > Unit::B);
+}
+
+#[test]
+fn none_1() {
+    #[derive(Educe)]
+    #[educe(PartialEq, PartialOrd)]
+    enum Enum {
+        Struct {
+            #[educe(PartialOrd(none = "last"))]
+            f1: Option<u8>,
+        },
+        Tuple(#[educe(PartialOrd(none = "last", reverse))] Option<u8>),
+    }
+
+    assert!(
+        Enum::Struct {
+            f1: None
+        } > Enum::Struct {
+            f1: Some(1)
+        }
+    );
+
+    assert!(Enum::Tuple(Some(2)) < Enum::Tuple(Some(1)));
+    assert!(Enum::Tuple(Some(1)) < Enum::Tuple(None));
+}
+
+#[test]
+#[allow(dead_code)]
+fn variant_only_1() {
+    #[derive(Educe)]
+    #[educe(PartialEq, PartialOrd(variant_only))]
+    enum Enum {
+        Struct { f1: u8 },
+        Tuple(u8),
+    }
+
+    assert!(
+        Enum::Struct {
+            f1: 2
+        } < Enum::Tuple(1)
+    );
+
+    assert_eq!(
+        Some(core::cmp::Ordering::Equal),
+        Enum::Tuple(2).partial_cmp(&Enum::Tuple(1))
+    );
+}
+
+#[test]
+fn cross_variant_1() {
+    #[derive(Educe)]
+    #[educe(PartialEq, PartialOrd(cross_variant = "none"))]
+    enum Enum {
+        Unit,
+        Struct {
+            f1: u8,
+        },
+        Tuple(u8),
+        #[educe(PartialOrd(cross_variant = "rank"))]
+        Unit2,
+        #[educe(PartialOrd(cross_variant = "rank"))]
+        Unit3,
+    }
+
+    assert_eq!(Some(Ordering::Equal), Enum::Unit.partial_cmp(&Enum::Unit));
+    assert_eq!(None, Enum::Unit.partial_cmp(&Enum::Tuple(1)));
+    assert_eq!(None, Enum::Tuple(1).partial_cmp(&Enum::Unit2));
+    assert_eq!(
+        None,
+        Enum::Struct {
+            f1: 1
+        }
+        .partial_cmp(&Enum::Tuple(1))
+    );
+    assert_eq!(
+        Some(Ordering::Less),
+        Enum::Struct {
+            f1: 1
+        }
+        .partial_cmp(&Enum::Struct {
+            f1: 2
+        })
+    );
+    assert_eq!(Some(Ordering::Greater), Enum::Tuple(2).partial_cmp(&Enum::Tuple(1)));
+    assert_eq!(Some(Ordering::Less), Enum::Unit2.partial_cmp(&Enum::Unit3));
+    assert_eq!(Some(Ordering::Equal), Enum::Unit3.partial_cmp(&Enum::Unit3));
+}
+
+#[test]
+fn cross_variant_2() {
+    #[derive(Educe)]
+    #[educe(PartialEq, PartialOrd)]
+    enum Enum {
+        Unit,
+        Tuple(u8),
+        #[educe(PartialOrd(cross_variant = "none"))]
+        Unknown,
+    }
+
+    assert_eq!(Some(Ordering::Less), Enum::Unit.partial_cmp(&Enum::Tuple(1)));
+    assert_eq!(None, Enum::Unit.partial_cmp(&Enum::Unknown));
+    assert_eq!(None, Enum::Unknown.partial_cmp(&Enum::Tuple(1)));
+    assert_eq!(Some(Ordering::Equal), Enum::Unknown.partial_cmp(&Enum::Unknown));
+}
+
+#[test]
+fn variant_rank_3() {
+    const PRIORITY_HIGH: u8 = 2;
+    const PRIORITY_LOW: u8 = 1;
+
+    #[derive(Educe)]
+    #[educe(PartialEq, PartialOrd)]
+    enum Enum {
+        #[educe(PartialOrd(rank = "PRIORITY_HIGH"))]
+        High,
+        #[educe(PartialOrd(rank = "PRIORITY_LOW"))]
+        Low,
+        #[educe(PartialOrd(rank = "discriminant"))]
+        Urgent = 3,
+    }
+
+    assert_eq!(Some(Ordering::Greater), Enum::High.partial_cmp(&Enum::Low));
+    assert_eq!(Some(Ordering::Less), Enum::High.partial_cmp(&Enum::Urgent));
+    assert_eq!(Some(Ordering::Equal), Enum::Low.partial_cmp(&Enum::Low));
+}
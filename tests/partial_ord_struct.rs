@@ -801,3 +801,135 @@ fn rank_4() {
     assert!(Tuple(2, 1) < Tuple(1, 2));
     assert!(Tuple(1, 2) > Tuple(2, 1));
 }
+
+#[test]
+fn reverse_1() {
+    #[derive(Educe)]
+    #[educe(PartialEq, PartialOrd)]
+    struct Struct {
+        f1: u8,
+        #[educe(PartialOrd(reverse))]
+        f2: u8,
+    }
+
+    #[derive(Educe)]
+    #[educe(PartialEq, PartialOrd(reverse))]
+    struct Tuple(u8, u8);
+
+    assert!(
+        Struct {
+            f1: 1,
+            f2: 1
+        } > Struct {
+            f1: 1,
+            f2: 2
+        }
+    );
+
+    assert!(
+        Struct {
+            f1: 1,
+            f2: 2
+        } < Struct {
+            f1: 2,
+            f2: 1
+        }
+    );
+
+    assert!(Tuple(2, 1) < Tuple(1, 2));
+    assert!(Tuple(1, 2) < Tuple(1, 1));
+}
+
+#[test]
+fn none_1() {
+    #[derive(Educe)]
+    #[educe(PartialEq, PartialOrd)]
+    struct Struct {
+        #[educe(PartialOrd(none = "last"))]
+        f1: Option<f64>,
+    }
+
+    #[derive(Educe)]
+    #[educe(PartialEq, PartialOrd)]
+    struct Tuple(#[educe(PartialOrd(none("last")))] Option<f64>);
+
+    assert!(
+        Struct {
+            f1: None
+        } > Struct {
+            f1: Some(1.0)
+        }
+    );
+
+    assert!(
+        Struct {
+            f1: Some(1.0)
+        } < Struct {
+            f1: Some(2.0)
+        }
+    );
+
+    assert!(Tuple(Some(1.0)) < Tuple(None));
+    assert_eq!(None, Tuple(Some(f64::NAN)).partial_cmp(&Tuple(Some(1.0))));
+}
+
+#[test]
+fn rhs_1() {
+    #[derive(Educe)]
+    #[educe(PartialEq(rhs = "u16"), PartialOrd(rhs = "u16"))]
+    struct Version(u16);
+
+    #[derive(Educe)]
+    #[educe(PartialEq(rhs = "u16"), PartialOrd(reverse, rhs = "u16"))]
+    struct Reversed(u16);
+
+    assert_eq!(Some(Ordering::Less), Version(1).partial_cmp(&2u16));
+    assert_eq!(Some(Ordering::Equal), Version(1).partial_cmp(&1u16));
+    assert_eq!(Some(Ordering::Greater), Version(2).partial_cmp(&1u16));
+    assert!(Version(1) < 2u16);
+
+    assert_eq!(Some(Ordering::Greater), Reversed(1).partial_cmp(&2u16));
+}
+
+#[test]
+fn rhs_2() {
+    struct Other {
+        f1: u8,
+        f2: u8,
+    }
+
+    impl PartialEq<Other> for Struct {
+        fn eq(&self, other: &Other) -> bool {
+            self.f1 == other.f1 && self.f2 == other.f2
+        }
+    }
+
+    #[derive(Educe)]
+    #[educe(PartialEq, PartialOrd(rhs = "Other"))]
+    struct Struct {
+        #[educe(PartialOrd(rank = 1))]
+        f1: u8,
+        #[educe(PartialOrd(rank = 0))]
+        f2: u8,
+    }
+
+    assert_eq!(
+        Some(Ordering::Greater),
+        Struct {
+            f1: 1, f2: 2
+        }
+        .partial_cmp(&Other {
+            f1: 2, f2: 1
+        })
+    );
+
+    assert_eq!(
+        Some(Ordering::Less),
+        Struct {
+            f1: 1, f2: 2
+        }
+        .partial_cmp(&Other {
+            f1: 2, f2: 2
+        })
+    );
+}
@@ -0,0 +1,31 @@
+#![cfg(all(feature = "PartialEq", feature = "PartialOrd"))]
+#![no_std]
+
+#[macro_use]
+extern crate educe;
+
+#[test]
+#[allow(dead_code)]
+fn bytes_1() {
+    #[derive(Educe)]
+    #[educe(PartialEq(bytes), PartialOrd(bytes))]
+    union Union {
+        f1: [u8; 2],
+        f2: u16,
+    }
+
+    assert!(
+        Union {
+            f1: [1, 2]
+        } < Union {
+            f1: [1, 3]
+        }
+    );
+    assert!(
+        Union {
+            f1: [2, 0]
+        } > Union {
+            f1: [1, 3]
+        }
+    );
+}